@@ -0,0 +1,160 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Data, DeriveInput, Fields, LitStr, Token, Variant};
+
+use crate::util::{check_path, path_params, snake_case};
+
+/// The parsed `#[route("/path", name = "name")]` attribute of a variant
+struct RouteAttr {
+    path: LitStr,
+    name: Option<LitStr>,
+}
+
+impl RouteAttr {
+    fn from_variant(variant: &Variant) -> syn::Result<Self> {
+        let mut attrs = variant
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("route"));
+        let Some(attr) = attrs.next() else {
+            return Err(syn::Error::new_spanned(
+                variant,
+                "missing `#[route(\"/path\")]` attribute",
+            ));
+        };
+        if let Some(extra) = attrs.next() {
+            return Err(syn::Error::new_spanned(
+                extra,
+                "only one `#[route]` attribute is allowed per variant",
+            ));
+        }
+
+        attr.parse_args_with(|input: syn::parse::ParseStream| {
+            let path: LitStr = input.parse()?;
+            check_path(&path)?;
+            let mut name = None;
+            if input.parse::<Option<Token![,]>>()?.is_some() && !input.is_empty() {
+                let key: syn::Ident = input.parse()?;
+                if key != "name" {
+                    return Err(syn::Error::new_spanned(key, "expected `name`"));
+                }
+                input.parse::<Token![=]>()?;
+                name = Some(input.parse()?);
+                input.parse::<Option<Token![,]>>()?;
+            }
+            Ok(Self { path, name })
+        })
+    }
+}
+
+pub(crate) fn expand(input: DeriveInput) -> syn::Result<TokenStream> {
+    let Data::Enum(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            &input,
+            "`NamedRoutes` can only be derived for enums",
+        ));
+    };
+    if data.variants.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input,
+            "`NamedRoutes` requires at least one variant",
+        ));
+    }
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let mut consts = Vec::new();
+    let mut const_idents = Vec::new();
+    let mut def_arms = Vec::new();
+    let mut param_arms = Vec::new();
+    let mut names: Vec<String> = Vec::new();
+
+    for variant in &data.variants {
+        let attr = RouteAttr::from_variant(variant)?;
+        let variant_ident = &variant.ident;
+        let snake = snake_case(&variant_ident.to_string());
+        let const_ident = format_ident!("{}", snake.to_uppercase());
+        let name = attr
+            .name
+            .unwrap_or_else(|| LitStr::new(&snake, variant_ident.span()));
+        if names.contains(&name.value()) {
+            return Err(syn::Error::new_spanned(
+                &name,
+                format!("duplicate route name `{}`", name.value()),
+            ));
+        }
+        names.push(name.value());
+        let path = &attr.path;
+
+        // The fields of the variant must exactly match the params in the path
+        let path_value = path.value();
+        let params = path_params(&path_value);
+        let fields: Vec<_> = match &variant.fields {
+            Fields::Named(fields) => fields
+                .named
+                .iter()
+                .filter_map(|field| field.ident.clone())
+                .collect(),
+            Fields::Unit => Vec::new(),
+            Fields::Unnamed(fields) => {
+                return Err(syn::Error::new_spanned(
+                    fields,
+                    "route parameters must be named fields",
+                ))
+            }
+        };
+        for param in &params {
+            if !fields.iter().any(|field| field == param) {
+                return Err(syn::Error::new_spanned(
+                    path,
+                    format!("path parameter `{param}` has no matching field"),
+                ));
+            }
+        }
+        for field in &fields {
+            if !params.iter().any(|param| field == param) {
+                return Err(syn::Error::new_spanned(
+                    field,
+                    format!("field `{field}` is not a parameter in `{path_value}`"),
+                ));
+            }
+        }
+
+        consts.push(quote! {
+            #[doc = concat!("The route definition of [`", stringify!(#ident), "::", stringify!(#variant_ident), "`]")]
+            pub const #const_ident: ::axum_named_routes::RouteDef =
+                ::axum_named_routes::RouteDef::new(#name, #path);
+        });
+        def_arms.push(quote! {
+            Self::#variant_ident { .. } => Self::#const_ident,
+        });
+        let keys = fields.iter().map(|field| field.to_string());
+        param_arms.push(quote! {
+            Self::#variant_ident { #(#fields),* } => ::axum_named_routes::Params::new()
+                #(.insert(#keys, #fields))*,
+        });
+        const_idents.push(const_ident);
+    }
+
+    Ok(quote! {
+        impl #impl_generics #ident #ty_generics #where_clause {
+            #(#consts)*
+        }
+
+        impl #impl_generics ::axum_named_routes::NamedRoutes for #ident #ty_generics #where_clause {
+            const ROUTES: &'static [::axum_named_routes::RouteDef] = &[#(Self::#const_idents),*];
+
+            fn route_def(&self) -> ::axum_named_routes::RouteDef {
+                match self {
+                    #(#def_arms)*
+                }
+            }
+
+            fn params(&self) -> ::axum_named_routes::Params {
+                match self {
+                    #(#param_arms)*
+                }
+            }
+        }
+    })
+}
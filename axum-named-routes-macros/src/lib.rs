@@ -0,0 +1,65 @@
+#![forbid(unsafe_code)]
+#![warn(missing_docs)]
+
+//! Macros for [`axum-named-routes`](https://docs.rs/axum-named-routes)
+//!
+//! These are re-exported by `axum-named-routes` when the `macros` feature is enabled
+//! and should be used from there.
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput};
+
+mod derive;
+mod named_routes;
+mod util;
+
+/// Derive `NamedRoutes` for an enum where every variant is a route
+///
+/// Check out the `NamedRoutes` trait in `axum-named-routes` for more information.
+#[proc_macro_derive(NamedRoutes, attributes(route))]
+pub fn derive_named_routes(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    derive::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Declare all route names and paths in one place so they can be checked at compile time
+///
+/// This defines a `NAMED_ROUTES` constant with the `RouteDef` of every route, and two
+/// macros that can be used after the declaration:
+/// - `route!("name")` gets the `RouteDef` of a route to register it with
+///   `NamedRouter::typed_route` or to look it up in `Routes`
+/// - `url!("name", param = value, ...)` generates the path of a route with the params
+///   filled in, it returns a `Result` since param values that are `.` or `..` path
+///   segments are rejected with `UrlError::DotSegment`
+///
+/// Using a name that is not declared, passing missing or unknown params to `url!` or
+/// declaring an invalid path is a compile error.
+/// ```
+/// use axum::routing::get;
+/// use axum_named_routes::{named_routes, NamedRouter, Routes};
+///
+/// named_routes! {
+///     "index" => "/",
+///     "users.show" => "/users/:id",
+/// }
+///
+/// async fn index(routes: Routes) -> String {
+///     url!("users.show", id = 4).unwrap() // == "/users/4"
+/// }
+///
+/// let app: NamedRouter = NamedRouter::new()
+///     .typed_route(route!("index"), get(index))
+///     .typed_route(route!("users.show"), get(|| async {}));
+///
+/// let routes: Routes = NAMED_ROUTES.iter().copied().collect();
+/// assert_eq!(routes.get("users.show"), Some(app.routes()["users.show"].path()));
+/// ```
+#[proc_macro]
+pub fn named_routes(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as named_routes::Input);
+    named_routes::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
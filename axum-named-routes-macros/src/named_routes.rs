@@ -0,0 +1,122 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    Ident, LitStr, Token,
+};
+
+use crate::util::{check_path, path_params};
+
+/// A single `"name" => "/path"` entry
+struct Entry {
+    name: LitStr,
+    path: LitStr,
+}
+
+impl Parse for Entry {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name: LitStr = input.parse()?;
+        input.parse::<Token![=>]>()?;
+        let path: LitStr = input.parse()?;
+        check_path(&path)?;
+        Ok(Self { name, path })
+    }
+}
+
+pub(crate) struct Input {
+    entries: Punctuated<Entry, Token![,]>,
+}
+
+impl Parse for Input {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(Self {
+            entries: Punctuated::parse_terminated(input)?,
+        })
+    }
+}
+
+pub(crate) fn expand(input: Input) -> syn::Result<TokenStream> {
+    let mut route_arms = Vec::new();
+    let mut url_arms = Vec::new();
+    let mut defs = Vec::new();
+
+    for (i, entry) in input.entries.iter().enumerate() {
+        let Entry { name, path } = entry;
+        if input
+            .entries
+            .iter()
+            .take(i)
+            .any(|e| e.name.value() == name.value())
+        {
+            return Err(syn::Error::new_spanned(
+                name,
+                format!("duplicate route name `{}`", name.value()),
+            ));
+        }
+
+        let path_value = path.value();
+        let params = path_params(&path_value)
+            .into_iter()
+            .map(|param| {
+                syn::parse_str::<Ident>(param).map_err(|_| {
+                    syn::Error::new_spanned(
+                        path,
+                        format!("path parameter `{param}` is not a valid identifier"),
+                    )
+                })
+            })
+            .collect::<syn::Result<Vec<_>>>()?;
+        let keys = params.iter().map(|param| param.to_string());
+        let generics: Vec<_> = (0..params.len())
+            .map(|i| format_ident!("__T{}", i))
+            .collect();
+
+        defs.push(quote! { ::axum_named_routes::RouteDef::new(#name, #path) });
+        route_arms.push(quote! {
+            (#name) => { ::axum_named_routes::RouteDef::new(#name, #path) };
+        });
+        // The params are checked by building a struct literal with exactly the
+        // route's params as fields, so missing and unknown params are compile errors
+        url_arms.push(quote! {
+            (#name $(, $key:ident = $value:expr)* $(,)?) => {{
+                #[allow(non_camel_case_types)]
+                struct __UrlParams<#(#generics),*> { #(#params: #generics),* }
+                #[allow(unused_variables)]
+                let __params = __UrlParams { $($key: $value),* };
+                ::axum_named_routes::__private::fill(
+                    #name,
+                    #path,
+                    ::axum_named_routes::Params::new() #(.insert(#keys, __params.#params))*,
+                )
+            }};
+        });
+    }
+
+    Ok(quote! {
+        /// Every route declared in `named_routes!`
+        #[allow(dead_code)]
+        const NAMED_ROUTES: &[::axum_named_routes::RouteDef] = &[#(#defs),*];
+
+        /// Get the [`RouteDef`](::axum_named_routes::RouteDef) of a route declared in
+        /// `named_routes!`, an undeclared name is a compile error
+        #[allow(unused_macros)]
+        macro_rules! route {
+            #(#route_arms)*
+            ($name:literal) => {
+                compile_error!(concat!("no route named `", $name, "` declared in `named_routes!`"))
+            };
+        }
+
+        /// Generate the path of a route declared in `named_routes!` by filling in its params,
+        /// an undeclared name or a missing or unknown param is a compile error and a `.` or
+        /// `..` param value is an error
+        #[allow(unused_macros)]
+        macro_rules! url {
+            #(#url_arms)*
+            ($name:literal $($rest:tt)*) => {
+                compile_error!(concat!("no route named `", $name, "` declared in `named_routes!`"))
+            };
+        }
+    })
+}
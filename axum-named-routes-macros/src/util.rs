@@ -0,0 +1,53 @@
+use syn::LitStr;
+
+/// Check a route path with the same rules as `RoutePath::parse` so invalid paths are
+/// compile errors instead of panics when the route is used
+pub(crate) fn check_path(path: &LitStr) -> syn::Result<()> {
+    let value = path.value();
+    let Some(rest) = value.strip_prefix('/') else {
+        return Err(syn::Error::new_spanned(path, "paths must start with a `/`"));
+    };
+    let segments: Vec<&str> = rest.split('/').collect();
+    for (i, segment) in segments.iter().enumerate() {
+        if matches!(*segment, ":" | "*") {
+            return Err(syn::Error::new_spanned(
+                path,
+                "parameters and wildcards must have a name",
+            ));
+        }
+        if segment.starts_with('*') && i + 1 < segments.len() {
+            return Err(syn::Error::new_spanned(
+                path,
+                "wildcards are only allowed at the end of a path",
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// The names of all `:param` and `*wildcard` segments in a route path
+pub(crate) fn path_params(path: &str) -> Vec<&str> {
+    path.split('/')
+        .filter_map(|segment| {
+            segment
+                .strip_prefix(':')
+                .or_else(|| segment.strip_prefix('*'))
+        })
+        .collect()
+}
+
+/// Convert a `PascalCase` identifier to `snake_case`
+pub(crate) fn snake_case(ident: &str) -> String {
+    let mut out = String::with_capacity(ident.len() + 4);
+    for (i, c) in ident.char_indices() {
+        if c.is_uppercase() {
+            if i > 0 {
+                out.push('_');
+            }
+            out.extend(c.to_lowercase());
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
@@ -0,0 +1,72 @@
+#![allow(clippy::unwrap_used)]
+
+use axum::routing::get;
+use axum_named_routes::{NamedRouter, NamedRoutes, RouteDef, UrlError};
+
+#[derive(NamedRoutes)]
+enum AppRoute {
+    #[route("/")]
+    Index,
+    #[route("/users/:id/posts/:post_id", name = "users.post")]
+    UserPost { id: u64, post_id: String },
+    #[route("/files/*path")]
+    Files { path: String },
+}
+
+async fn dummy() {}
+
+#[test]
+fn route_defs() {
+    assert_eq!(AppRoute::INDEX, RouteDef::new("index", "/"));
+    assert_eq!(
+        AppRoute::USER_POST,
+        RouteDef::new("users.post", "/users/:id/posts/:post_id")
+    );
+    assert_eq!(AppRoute::FILES.name(), "files");
+    assert_eq!(AppRoute::ROUTES.len(), 3);
+    assert_eq!(
+        AppRoute::UserPost {
+            id: 1,
+            post_id: "a".into()
+        }
+        .route_def(),
+        AppRoute::USER_POST
+    );
+}
+
+#[test]
+fn typed_urls() {
+    let post = AppRoute::UserPost {
+        id: 4,
+        post_id: "hello world".into(),
+    };
+    assert_eq!(AppRoute::Index.to_path().unwrap(), "/");
+    assert_eq!(post.to_path().unwrap(), "/users/4/posts/hello%20world");
+    assert_eq!(
+        AppRoute::Files {
+            path: "css/app.css".into()
+        }
+        .to_path()
+        .unwrap(),
+        "/files/css/app.css"
+    );
+
+    // dot segments are rejected like in `Routes::typed_url`
+    let dots = AppRoute::Files {
+        path: "css/../app.css".into(),
+    };
+    assert_eq!(
+        dots.to_path(),
+        Err(UrlError::DotSegment {
+            route: "files".into(),
+            param: "path".into(),
+        })
+    );
+
+    let app = NamedRouter::<()>::new()
+        .typed_route(AppRoute::INDEX, get(dummy))
+        .typed_route(AppRoute::USER_POST, get(dummy));
+    let routes = app.routes();
+    assert_eq!(routes["index"].path(), "/");
+    assert_eq!(routes["users.post"].path(), "/users/:id/posts/:post_id");
+}
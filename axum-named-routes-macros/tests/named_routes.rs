@@ -0,0 +1,51 @@
+#![allow(clippy::unwrap_used)]
+
+use axum::routing::get;
+use axum_named_routes::{named_routes, NamedRouter, RouteDef, RouteInfo, Routes, UrlError};
+
+named_routes! {
+    "index" => "/",
+    "users.post" => "/users/:id/posts/:post_id",
+    "files" => "/files/*path",
+}
+
+async fn dummy() {}
+
+#[test]
+fn route_macro() {
+    assert_eq!(route!("index"), RouteDef::new("index", "/"));
+    assert_eq!(route!("users.post").path(), "/users/:id/posts/:post_id");
+    assert_eq!(NAMED_ROUTES.len(), 3);
+
+    let app = NamedRouter::<()>::new()
+        .typed_route(route!("index"), get(dummy))
+        .typed_route(route!("users.post"), get(dummy))
+        .typed_route(route!("files"), get(dummy));
+    let routes: Routes = NAMED_ROUTES.iter().copied().collect();
+    for def in NAMED_ROUTES {
+        assert_eq!(
+            routes.get(def.name()),
+            app.routes().get(def.name()).map(RouteInfo::path)
+        );
+    }
+}
+
+#[test]
+fn url_macro() {
+    assert_eq!(url!("index").unwrap(), "/");
+    assert_eq!(
+        url!("users.post", post_id = "hello world", id = 4).unwrap(),
+        "/users/4/posts/hello%20world"
+    );
+    assert_eq!(
+        url!("files", path = "css/app.css",).unwrap(),
+        "/files/css/app.css"
+    );
+    assert_eq!(
+        url!("users.post", id = "..", post_id = 1),
+        Err(UrlError::DotSegment {
+            route: "users.post".into(),
+            param: "id".into(),
+        })
+    );
+}
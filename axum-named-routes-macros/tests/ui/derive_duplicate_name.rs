@@ -0,0 +1,11 @@
+use axum_named_routes::NamedRoutes;
+
+#[derive(NamedRoutes)]
+enum AppRoute {
+    #[route("/users", name = "users")]
+    Users,
+    #[route("/people", name = "users")]
+    People,
+}
+
+fn main() {}
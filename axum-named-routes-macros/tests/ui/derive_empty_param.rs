@@ -0,0 +1,9 @@
+use axum_named_routes::NamedRoutes;
+
+#[derive(NamedRoutes)]
+enum AppRoute {
+    #[route("/users/:")]
+    User,
+}
+
+fn main() {}
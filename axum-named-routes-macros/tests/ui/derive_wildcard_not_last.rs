@@ -0,0 +1,9 @@
+use axum_named_routes::NamedRoutes;
+
+#[derive(NamedRoutes)]
+enum AppRoute {
+    #[route("/files/*rest/x")]
+    Files { rest: String },
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use axum_named_routes::named_routes;
+
+named_routes! {
+    "users.show" => "/users/:id",
+}
+
+fn main() {
+    let _ = url!("users.show", id = 4, page = 2);
+}
@@ -0,0 +1,7 @@
+use axum_named_routes::named_routes;
+
+named_routes! {
+    "users.post" => "/users/:id/posts/:post-id",
+}
+
+fn main() {}
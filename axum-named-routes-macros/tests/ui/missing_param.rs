@@ -0,0 +1,9 @@
+use axum_named_routes::named_routes;
+
+named_routes! {
+    "users.post" => "/users/:id/posts/:post_id",
+}
+
+fn main() {
+    let _ = url!("users.post", id = 4);
+}
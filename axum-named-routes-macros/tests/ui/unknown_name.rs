@@ -0,0 +1,10 @@
+use axum_named_routes::named_routes;
+
+named_routes! {
+    "users.show" => "/users/:id",
+}
+
+fn main() {
+    let _ = route!("users.shw");
+    let _ = url!("users.shw", id = 4);
+}
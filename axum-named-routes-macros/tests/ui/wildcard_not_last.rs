@@ -0,0 +1,7 @@
+use axum_named_routes::named_routes;
+
+named_routes! {
+    "files" => "/files/*rest/x",
+}
+
+fn main() {}
@@ -0,0 +1,53 @@
+use axum::routing::get;
+use axum_named_routes::{NamedRouter, Routes};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+async fn dummy() {}
+
+/// Build routes with `count` routes nested in groups of ten
+fn routes(count: usize) -> Routes {
+    let mut app = NamedRouter::<()>::new();
+    for group in 0..count / 10 {
+        let mut nested = NamedRouter::new();
+        for i in 0..10 {
+            nested = nested.route(format!("route{i}"), format!("/route{i}/:id"), get(dummy));
+        }
+        app = app.nest(format!("group{group}"), format!("/group{group}"), nested);
+    }
+    app.into_parts().1
+}
+
+fn reverse_lookup(c: &mut Criterion) {
+    let mut group = c.benchmark_group("reverse_lookup");
+    for count in [10, 100, 1000] {
+        let routes = routes(count);
+        let last = count / 10 - 1;
+        let template = format!("/group{last}/route9/:id");
+        let concrete = format!("/group{last}/route9/42");
+
+        group.bench_with_input(
+            BenchmarkId::new("linear_scan", count),
+            &template,
+            |b, path| {
+                b.iter(|| {
+                    routes
+                        .iter()
+                        .find(|(_, info)| info.path() == path.as_str())
+                        .map(|(name, _)| name)
+                })
+            },
+        );
+        group.bench_with_input(BenchmarkId::new("find", count), &template, |b, path| {
+            b.iter(|| routes.find(black_box(path)))
+        });
+        group.bench_with_input(
+            BenchmarkId::new("find_match", count),
+            &concrete,
+            |b, path| b.iter(|| routes.find_match(black_box(path))),
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, reverse_lookup);
+criterion_main!(benches);
@@ -1,6 +1,5 @@
-use axum::routing::get;
-use axum_named_routes::{NamedRouter, Routes};
-use std::{net::SocketAddr, path::PathBuf};
+use axum_named_routes::{routing::get, NamedRouter, Routes};
+use tokio::net::TcpListener;
 
 async fn index() -> &'static str {
     "Hello, World!"
@@ -11,7 +10,7 @@ async fn nested_other(routes: Routes) {
     // this could panic if the name is not in the Routes map
     // but we know that it is because we got here
     let this_route = routes.has("ui.other");
-    assert_eq!(this_route, &PathBuf::from("/ui/other"));
+    assert_eq!(this_route, "/ui/other");
 }
 
 async fn other(routes: Routes) {
@@ -32,9 +31,8 @@ async fn main() {
         .nest("ui", "/ui/", ui)
         .route("other", "/other", get(other));
 
-    let addr = SocketAddr::from(([127, 0, 0, 1], 3000));
-    axum::Server::bind(&addr)
-        .serve(app.into_make_service())
+    let listener = TcpListener::bind("127.0.0.1:3000").await.unwrap();
+    axum::serve(listener, app.into_make_service())
         .await
         .unwrap();
 }
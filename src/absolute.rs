@@ -0,0 +1,345 @@
+//! Absolute URL generation using the scheme and host of the current request
+//!
+//! Check out [`AbsoluteRoutes`] for more information
+
+use std::{net::IpAddr, str::FromStr};
+
+use axum::{
+    extract::{rejection::ExtensionRejection, FromRequestParts},
+    http::{header, request::Parts, uri::Authority, HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+};
+
+use crate::{Routes, UrlError};
+
+/// Which proxies are trusted to set the `Forwarded`, `X-Forwarded-Proto` and
+/// `X-Forwarded-Host` headers used by [`AbsoluteRoutes`]
+///
+/// These headers can be set by any client so they should only be trusted when the
+/// application is deployed behind a proxy that overwrites them.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ProxyPolicy {
+    /// Never trust forwarding headers, only the `Host` header is used
+    #[default]
+    Ignore,
+    /// Always trust forwarding headers
+    TrustAll,
+    /// Trust forwarding headers only when the peer address is one of these addresses
+    ///
+    /// This requires the router to be served using
+    /// [`into_make_service_with_connect_info`](crate::NamedRouter::into_make_service_with_connect_info)
+    /// with a [`SocketAddr`](std::net::SocketAddr), otherwise forwarding headers are never
+    /// trusted. This requires the `tokio` feature.
+    TrustPeers(Vec<IpAddr>),
+}
+
+impl ProxyPolicy {
+    fn trusts(&self, parts: &Parts) -> bool {
+        match self {
+            Self::Ignore => false,
+            Self::TrustAll => true,
+            Self::TrustPeers(peers) => peer_ip(parts).is_some_and(|ip| peers.contains(&ip)),
+        }
+    }
+}
+
+#[cfg(feature = "tokio")]
+fn peer_ip(parts: &Parts) -> Option<IpAddr> {
+    use axum::extract::ConnectInfo;
+    use std::net::SocketAddr;
+
+    parts
+        .extensions
+        .get::<ConnectInfo<SocketAddr>>()
+        .map(|ConnectInfo(addr)| addr.ip())
+}
+
+/// `ConnectInfo` is only available with the `tokio` feature so no peer is trusted without it
+#[cfg(not(feature = "tokio"))]
+fn peer_ip(_parts: &Parts) -> Option<IpAddr> {
+    None
+}
+
+/// An extractor for generating absolute URLs to named routes
+///
+/// The scheme and host are taken from the configured
+/// [`base_url`](crate::NamedRouter::base_url) if there is one. Otherwise they are taken
+/// from the request using the `Host` header, or the forwarding headers when the
+/// [`ProxyPolicy`] trusts the peer.
+/// ```
+/// use axum::routing::get;
+/// use axum_named_routes::{AbsoluteRoutes, NamedRouter};
+///
+/// async fn user(routes: AbsoluteRoutes) -> String {
+///     routes.absolute_url_for("user", [("id", "4")]).unwrap()
+///     // == "http://localhost:3000/users/4"
+/// }
+///
+/// let app: NamedRouter = NamedRouter::new()
+///     .route("user", "/users/:id", get(user));
+/// ```
+#[derive(Clone, Debug)]
+pub struct AbsoluteRoutes {
+    routes: Routes,
+    origin: String,
+}
+
+impl AbsoluteRoutes {
+    /// The scheme, host and optional path prefix URLs are generated with,
+    /// like `https://example.com`
+    pub fn origin(&self) -> &str {
+        &self.origin
+    }
+
+    /// The underlying [`Routes`]
+    pub fn routes(&self) -> &Routes {
+        &self.routes
+    }
+
+    /// The same as [`Routes::url_for`] but prefixed with the [`origin`](AbsoluteRoutes::origin)
+    pub fn absolute_url_for<P: serde::Serialize>(
+        &self,
+        name: &str,
+        params: P,
+    ) -> Result<String, UrlError> {
+        Ok(self.origin.clone() + &self.routes.url_for(name, params)?)
+    }
+
+    /// The same as [`Routes::url_for_query`] but prefixed with the
+    /// [`origin`](AbsoluteRoutes::origin)
+    pub fn absolute_url_for_query<P: serde::Serialize>(
+        &self,
+        name: &str,
+        params: P,
+    ) -> Result<String, UrlError> {
+        Ok(self.origin.clone() + &self.routes.url_for_query(name, params)?)
+    }
+}
+
+#[axum::async_trait]
+impl<S: Send + Sync> FromRequestParts<S> for AbsoluteRoutes {
+    type Rejection = AbsoluteRoutesRejection;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let routes = Routes::from_request_parts(parts, state).await?;
+        let origin = match routes.base_url() {
+            Some(base_url) => base_url.to_owned(),
+            None => request_origin(parts, routes.proxy_policy().trusts(parts))
+                .ok_or(AbsoluteRoutesRejection::MissingHost)?,
+        };
+        Ok(Self { routes, origin })
+    }
+}
+
+/// The rejection used for [`AbsoluteRoutes`]
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum AbsoluteRoutesRejection {
+    /// The [`Routes`] extension is missing
+    MissingRoutes(ExtensionRejection),
+    /// The request has no valid host to generate URLs with
+    MissingHost,
+}
+
+impl From<ExtensionRejection> for AbsoluteRoutesRejection {
+    fn from(rejection: ExtensionRejection) -> Self {
+        Self::MissingRoutes(rejection)
+    }
+}
+
+impl IntoResponse for AbsoluteRoutesRejection {
+    fn into_response(self) -> Response {
+        match self {
+            Self::MissingRoutes(rejection) => rejection.into_response(),
+            Self::MissingHost => {
+                (StatusCode::BAD_REQUEST, "Missing or invalid host").into_response()
+            }
+        }
+    }
+}
+
+/// Build `scheme://host` from the request, returns `None` if there is no valid host
+fn request_origin(parts: &Parts, trust_forwarded: bool) -> Option<String> {
+    let (mut scheme, mut host) = (None, None);
+    if trust_forwarded {
+        (scheme, host) = forwarded(&parts.headers);
+        if scheme.is_none() {
+            scheme = header_str(&parts.headers, "x-forwarded-proto")
+                .and_then(|s| s.split(',').next())
+                .map(str::trim);
+        }
+        if host.is_none() {
+            host = header_str(&parts.headers, "x-forwarded-host")
+                .and_then(|s| s.split(',').next())
+                .map(str::trim);
+        }
+    }
+
+    let host = host
+        .or_else(|| header_str(&parts.headers, header::HOST.as_str()))
+        .or_else(|| parts.uri.authority().map(Authority::as_str))?;
+    // Reject anything that is not a plain authority so headers can not inject into the URL
+    let host = Authority::from_str(host).ok()?;
+    if host.as_str().contains('@') {
+        return None;
+    }
+
+    let scheme = match scheme.or_else(|| parts.uri.scheme_str()) {
+        Some(scheme) if scheme.eq_ignore_ascii_case("https") => "https",
+        _ => "http",
+    };
+    Some(format!("{scheme}://{host}"))
+}
+
+/// Parse the `proto` and `host` of the first element of the `Forwarded` header
+fn forwarded(headers: &HeaderMap) -> (Option<&str>, Option<&str>) {
+    let (mut proto, mut host) = (None, None);
+    let Some(first) =
+        header_str(headers, header::FORWARDED.as_str()).and_then(|value| value.split(',').next())
+    else {
+        return (proto, host);
+    };
+    for pair in first.split(';') {
+        let Some((key, value)) = pair.split_once('=') else {
+            continue;
+        };
+        let value = value.trim().trim_matches('"');
+        match key.trim() {
+            k if k.eq_ignore_ascii_case("proto") => proto = Some(value),
+            k if k.eq_ignore_ascii_case("host") => host = Some(value),
+            _ => {}
+        }
+    }
+    (proto, host)
+}
+
+fn header_str<'a>(headers: &'a HeaderMap, name: &str) -> Option<&'a str> {
+    headers.get(name).and_then(|value| value.to_str().ok())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use std::net::SocketAddr;
+
+    use axum::{
+        extract::{ConnectInfo, FromRequestParts},
+        http::Request,
+        routing::get,
+    };
+
+    use crate::{AbsoluteRoutes, NamedRouter, ProxyPolicy, Routes, UrlError};
+
+    async fn dummy() {}
+
+    fn routes(router: NamedRouter) -> Routes {
+        router
+            .route("user", "/users/:id", get(dummy))
+            .into_parts()
+            .1
+    }
+
+    async fn origin(routes: Routes, req: Request<()>) -> Option<String> {
+        let (mut parts, _) = req.into_parts();
+        parts.extensions.insert(routes);
+        AbsoluteRoutes::from_request_parts(&mut parts, &())
+            .await
+            .ok()
+            .map(|routes| routes.origin().to_owned())
+    }
+
+    #[tokio::test]
+    async fn request_origin() {
+        let plain = routes(NamedRouter::new());
+        let req = || {
+            Request::builder()
+                .uri("/users/4")
+                .header("host", "example.com:8080")
+                .header("x-forwarded-proto", "https")
+                .header("x-forwarded-host", "proxy.example.com")
+        };
+
+        assert_eq!(
+            origin(plain.clone(), req().body(()).unwrap())
+                .await
+                .unwrap(),
+            "http://example.com:8080"
+        );
+        assert_eq!(
+            origin(plain.clone(), Request::new(())).await,
+            None,
+            "requests without a host are rejected"
+        );
+        assert_eq!(
+            origin(
+                plain,
+                Request::builder()
+                    .header("host", "evil.com/path")
+                    .body(())
+                    .unwrap()
+            )
+            .await,
+            None,
+        );
+
+        let trusted = routes(NamedRouter::new().trust_proxy(ProxyPolicy::TrustAll));
+        assert_eq!(
+            origin(trusted.clone(), req().body(()).unwrap())
+                .await
+                .unwrap(),
+            "https://proxy.example.com"
+        );
+        let forwarded = req()
+            .header(
+                "forwarded",
+                "for=1.2.3.4;proto=https;host=\"fwd.example.com\", for=5.6.7.8",
+            )
+            .body(())
+            .unwrap();
+        assert_eq!(
+            origin(trusted, forwarded).await.unwrap(),
+            "https://fwd.example.com"
+        );
+
+        let peer: SocketAddr = ([10, 0, 0, 1], 80).into();
+        let peers =
+            routes(NamedRouter::new().trust_proxy(ProxyPolicy::TrustPeers(vec![peer.ip()])));
+        assert_eq!(
+            origin(peers.clone(), req().body(()).unwrap())
+                .await
+                .unwrap(),
+            "http://example.com:8080"
+        );
+        let from_peer = req().extension(ConnectInfo(peer)).body(()).unwrap();
+        assert_eq!(
+            origin(peers, from_peer).await.unwrap(),
+            "https://proxy.example.com"
+        );
+    }
+
+    #[tokio::test]
+    async fn base_url() {
+        let with_base = routes(NamedRouter::new().base_url("https://example.com/app/"));
+        assert_eq!(
+            with_base.absolute_url_for("user", [("id", "4")]).unwrap(),
+            "https://example.com/app/users/4"
+        );
+
+        let req = Request::builder()
+            .header("host", "other.com")
+            .body(())
+            .unwrap();
+        assert_eq!(
+            origin(with_base, req).await.unwrap(),
+            "https://example.com/app"
+        );
+
+        let without = routes(NamedRouter::new());
+        assert_eq!(
+            without.absolute_url_for("user", [("id", "4")]),
+            Err(UrlError::NoBaseUrl)
+        );
+    }
+}
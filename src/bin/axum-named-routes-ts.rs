@@ -0,0 +1,78 @@
+//! Generate TypeScript route helpers from a route manifest
+//!
+//! Reads the JSON served by `NamedRouter::manifest_route` or serialized from
+//! `Routes::manifest` and writes a TypeScript module with a function for every route.
+//!
+//! ```text
+//! axum-named-routes-ts [MANIFEST] [-o OUTPUT]
+//! ```
+//!
+//! The manifest is read from stdin when `MANIFEST` is missing or `-`, the module is
+//! written to stdout when there is no output file.
+
+use std::{
+    fs,
+    io::{self, Read, Write},
+    process::ExitCode,
+};
+
+use axum_named_routes::manifest::{Manifest, SCHEMA_VERSION};
+
+const USAGE: &str = "usage: axum-named-routes-ts [MANIFEST] [-o OUTPUT]";
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("axum-named-routes-ts: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run() -> Result<(), String> {
+    let (mut input, mut output) = (None, None);
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-o" | "--output" => output = Some(args.next().ok_or(USAGE)?),
+            "-h" | "--help" => {
+                println!("{USAGE}");
+                return Ok(());
+            }
+            _ if input.is_none() => input = Some(arg),
+            _ => return Err(USAGE.to_owned()),
+        }
+    }
+
+    let json = match input.as_deref() {
+        None | Some("-") => {
+            let mut json = String::new();
+            io::stdin()
+                .read_to_string(&mut json)
+                .map_err(|err| format!("failed to read stdin: {err}"))?;
+            json
+        }
+        Some(path) => {
+            fs::read_to_string(path).map_err(|err| format!("failed to read `{path}`: {err}"))?
+        }
+    };
+    let manifest: Manifest =
+        serde_json::from_str(&json).map_err(|err| format!("invalid manifest: {err}"))?;
+    if manifest.version != SCHEMA_VERSION {
+        return Err(format!(
+            "unsupported manifest version {}, expected {SCHEMA_VERSION}",
+            manifest.version
+        ));
+    }
+
+    let ts = manifest.to_typescript();
+    match output {
+        Some(path) => {
+            fs::write(&path, ts).map_err(|err| format!("failed to write `{path}`: {err}"))
+        }
+        None => io::stdout()
+            .write_all(ts.as_bytes())
+            .map_err(|err| format!("failed to write stdout: {err}")),
+    }
+}
@@ -0,0 +1,185 @@
+//! Extracting the name of the route that matched the current request
+//!
+//! Check out [`CurrentRoute`] for more information
+
+use axum::{
+    extract::{rejection::ExtensionRejection, FromRequestParts, MatchedPath, OriginalUri},
+    http::{request::Parts, StatusCode},
+    response::{IntoResponse, Response},
+};
+
+use crate::{RouteInfo, Routes};
+
+/// An extractor for the name of the route that matched the request
+///
+/// This uses axum's [`MatchedPath`] to find the route so it can also be used in
+/// middleware added with [`route_layer`](crate::NamedRouter::route_layer). When multiple
+/// names share the same path the one that accepts the request method is used, this needs
+/// the methods recorded by [`routing`](crate::routing). Routes with
+/// [`Unknown`](crate::routing::Methods::Unknown) methods are used when no route is known to
+/// accept the method, and the first name in alphabetical order if there are several.
+/// ```
+/// use axum::routing::get;
+/// use axum_named_routes::{CurrentRoute, NamedRouter};
+///
+/// async fn index(current: CurrentRoute) -> String {
+///     current.name().to_owned() // == "index"
+/// }
+///
+/// let app: NamedRouter = NamedRouter::new()
+///     .route("index", "/", get(index));
+/// ```
+#[derive(Clone, Debug)]
+pub struct CurrentRoute {
+    routes: Routes,
+    name: String,
+}
+
+impl CurrentRoute {
+    /// The name of the route that matched the request
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// All information about the route that matched the request
+    pub fn info(&self) -> &RouteInfo {
+        // The name was taken from the routes so it always exists
+        self.routes
+            .info(&self.name)
+            .expect("current route to exist in routes")
+    }
+
+    /// The [`Routes`] the current route was found in
+    pub fn routes(&self) -> &Routes {
+        &self.routes
+    }
+}
+
+#[axum::async_trait]
+impl<S: Send + Sync> FromRequestParts<S> for CurrentRoute {
+    type Rejection = CurrentRouteRejection;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let routes = Routes::from_request_parts(parts, state).await?;
+        let matched = parts
+            .extensions
+            .get::<MatchedPath>()
+            .and_then(|matched| routes.name_for_match(matched.as_str(), &parts.method));
+        let name = matched
+            .or_else(|| {
+                // below the mount point of a nested service there is no matched path and
+                // the uri has the mount point stripped, so use the original uri
+                let path = parts
+                    .extensions
+                    .get::<OriginalUri>()
+                    .map_or(parts.uri.path(), |uri| uri.path());
+                routes.mount_for(path)
+            })
+            .ok_or(CurrentRouteRejection::NotNamed)?
+            .to_owned();
+        Ok(Self { routes, name })
+    }
+}
+
+/// The rejection used for [`CurrentRoute`]
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum CurrentRouteRejection {
+    /// The [`Routes`] extension is missing
+    MissingRoutes(ExtensionRejection),
+    /// The request did not match a named route, like when it is handled by a fallback
+    NotNamed,
+}
+
+impl From<ExtensionRejection> for CurrentRouteRejection {
+    fn from(rejection: ExtensionRejection) -> Self {
+        Self::MissingRoutes(rejection)
+    }
+}
+
+impl IntoResponse for CurrentRouteRejection {
+    fn into_response(self) -> Response {
+        match self {
+            Self::MissingRoutes(rejection) => rejection.into_response(),
+            Self::NotNamed => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "No named route matched the request",
+            )
+                .into_response(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use axum::{
+        body::{to_bytes, Body},
+        http::{Method, Request},
+    };
+    use tower::ServiceExt;
+
+    use crate::{
+        routing::{get, post},
+        CurrentRoute, NamedRouter,
+    };
+
+    async fn current(current: Option<CurrentRoute>) -> String {
+        current.map_or_else(|| "none".to_owned(), |c| c.name().to_owned())
+    }
+
+    async fn call(app: &axum::Router, method: Method, uri: &str) -> String {
+        let req = Request::builder()
+            .method(method)
+            .uri(uri)
+            .body(Body::empty())
+            .unwrap();
+        let body = app.clone().oneshot(req).await.unwrap().into_body();
+        let bytes = to_bytes(body, usize::MAX).await.unwrap();
+        String::from_utf8(bytes.to_vec()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn current_route() {
+        let users = NamedRouter::new()
+            .route("show", "/:id", get(current))
+            .route("update", "/:id", post(current))
+            .route("archive", "/:id/archive", axum::routing::post(current))
+            .route("archived", "/:id/archive", get(current));
+        let app = NamedRouter::new()
+            .route("index", "/", get(current))
+            .nest("users", "/users", users)
+            .fallback(current)
+            .into_router();
+
+        assert_eq!(call(&app, Method::GET, "/").await, "index");
+        assert_eq!(call(&app, Method::GET, "/users/4").await, "users.show");
+        assert_eq!(call(&app, Method::POST, "/users/4").await, "users.update");
+        // known methods are preferred over unknown ones
+        let archive = "/users/4/archive";
+        assert_eq!(call(&app, Method::GET, archive).await, "users.archived");
+        assert_eq!(call(&app, Method::POST, archive).await, "users.archive");
+        assert_eq!(call(&app, Method::GET, "/missing").await, "none");
+    }
+
+    #[tokio::test]
+    async fn nested_service() {
+        let (app, routes) = NamedRouter::new()
+            .route("index", "/", get(current))
+            .nest_service("static", "/static", axum::routing::get(current))
+            .fallback(current)
+            .into_parts();
+
+        for uri in ["/static", "/static/", "/static/css/app.css"] {
+            assert_eq!(call(&app, Method::GET, uri).await, "static");
+        }
+        assert_eq!(call(&app, Method::GET, "/").await, "index");
+        assert_eq!(call(&app, Method::GET, "/other").await, "none");
+
+        assert_eq!(routes.find_match("/static").unwrap().0, "static");
+        let (name, params) = routes.find_match("/static/css/app.css").unwrap();
+        assert_eq!(name, "static");
+        assert_eq!(params.get("path"), Some("css/app.css"));
+    }
+}
@@ -0,0 +1,259 @@
+//! Errors returned while building a [`NamedRouter`](crate::NamedRouter) and looking up
+//! [`Routes`](crate::Routes)
+
+use std::fmt;
+
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+
+/// The error returned by the fallible `try_*` methods on [`NamedRouter`](crate::NamedRouter)
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum NamedRouterError {
+    /// A route name was registered more than once
+    DuplicateName {
+        /// The route name that is already in use
+        name: String,
+        /// The path the name is already registered to
+        existing: String,
+        /// The path that could not be registered
+        new: String,
+    },
+    /// A route name was declared for a path that does not exist in the nested router
+    UnknownPath {
+        /// The declared route name
+        name: String,
+        /// The declared path
+        path: String,
+    },
+    /// The routes of a plain axum [`Router`](axum::Router) could not be read
+    ///
+    /// axum does not expose the routes of a router so they are read from its `Debug`
+    /// output, this happens if that changes in a new axum version.
+    UnreadableRouter,
+    /// Route names declared with [`expect_routes`](crate::NamedRouter::expect_routes) are
+    /// not routes
+    MissingRoutes(Vec<RouteNotFound>),
+}
+
+impl fmt::Display for NamedRouterError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DuplicateName {
+                name,
+                existing,
+                new,
+            } => write!(
+                f,
+                "Overlapping route name. Name `{name}` is already used for `{existing}` and cannot be used for `{new}`"
+            ),
+            Self::UnknownPath { name, path } => write!(
+                f,
+                "Route `{name}` is declared with the path `{path}` which is not a route in the nested router"
+            ),
+            Self::UnreadableRouter => f.write_str(
+                "The routes of the axum Router could not be read, this axum version is not supported",
+            ),
+            Self::MissingRoutes(missing) => {
+                f.write_str("Expected routes are missing:")?;
+                for err in missing {
+                    write!(f, "\n  {err}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for NamedRouterError {}
+
+/// The maximum number of names suggested by [`RouteNotFound`]
+const MAX_SUGGESTIONS: usize = 3;
+
+/// The error returned by [`Routes::try_get`](crate::Routes::try_get) when no route has the name
+///
+/// It contains the names of existing routes that are close to the requested one and, if
+/// the name has a nest prefix like `ui.`, all routes under that prefix so typos are easy
+/// to spot. As a response this is a `500 Internal Server Error` because a missing route
+/// name is a bug in the application, the details are only logged.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct RouteNotFound {
+    /// The requested route name
+    pub name: String,
+    /// Existing route names close to the requested one, the closest first
+    pub suggestions: Vec<String>,
+    /// The longest nest prefix of the name that has routes, without the trailing separator
+    pub prefix: Option<String>,
+    /// All route names under [`prefix`](RouteNotFound::prefix) in alphabetical order
+    pub prefix_routes: Vec<String>,
+}
+
+impl RouteNotFound {
+    pub(crate) fn new(name: &str, names: &[&str], separator: &str) -> Self {
+        // allow roughly one typo for every three characters
+        let max_distance = (name.chars().count() / 3).max(1);
+        let mut close: Vec<(usize, &str)> = names
+            .iter()
+            .map(|&known| (edit_distance(name, known), known))
+            .filter(|&(distance, _)| distance <= max_distance)
+            .collect();
+        close.sort_unstable();
+
+        let (mut prefix, mut prefix_routes) = (None, Vec::new());
+        if !separator.is_empty() {
+            let mut parts: Vec<&str> = name.split(separator).collect();
+            parts.pop();
+            while !parts.is_empty() {
+                let nest = parts.join(separator);
+                let under = format!("{nest}{separator}");
+                prefix_routes = names
+                    .iter()
+                    .filter(|known| known.starts_with(&under))
+                    .map(|&known| known.to_owned())
+                    .collect();
+                if !prefix_routes.is_empty() {
+                    prefix_routes.sort_unstable();
+                    prefix = Some(nest);
+                    break;
+                }
+                parts.pop();
+            }
+        }
+
+        Self {
+            name: name.to_owned(),
+            suggestions: close
+                .into_iter()
+                .take(MAX_SUGGESTIONS)
+                .map(|(_, known)| known.to_owned())
+                .collect(),
+            prefix,
+            prefix_routes,
+        }
+    }
+}
+
+impl fmt::Display for RouteNotFound {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Route `{}` does not exist", self.name)?;
+        if let Some((first, rest)) = self.suggestions.split_first() {
+            write!(f, ", did you mean `{first}`")?;
+            for name in rest {
+                write!(f, ", `{name}`")?;
+            }
+            f.write_str("?")?;
+        }
+        if let (Some(prefix), Some((first, rest))) =
+            (&self.prefix, self.prefix_routes.split_first())
+        {
+            write!(f, " (routes under `{prefix}`: `{first}`")?;
+            for name in rest {
+                write!(f, ", `{name}`")?;
+            }
+            f.write_str(")")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for RouteNotFound {}
+
+impl IntoResponse for RouteNotFound {
+    fn into_response(self) -> Response {
+        tracing::error!("{self}");
+        (StatusCode::INTERNAL_SERVER_ERROR, "Unknown route name").into_response()
+    }
+}
+
+/// The optimal string alignment distance between two strings
+///
+/// This is the Levenshtein distance where swapping two adjacent characters also counts as
+/// a single edit, so the most common typo like `usres` is as close as a missing character.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let (a, b): (Vec<char>, Vec<char>) = (a.chars().collect(), b.chars().collect());
+    let mut before = vec![0; b.len() + 1];
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut curr = vec![0; b.len() + 1];
+    for (i, &ca) in a.iter().enumerate() {
+        curr[0] = i + 1;
+        for (j, &cb) in b.iter().enumerate() {
+            let substitute = prev[j] + usize::from(ca != cb);
+            let mut distance = substitute.min(prev[j + 1] + 1).min(curr[j] + 1);
+            if i > 0 && j > 0 && ca == b[j - 1] && a[i - 1] == cb {
+                distance = distance.min(before[j - 1] + 1);
+            }
+            curr[j + 1] = distance;
+        }
+        std::mem::swap(&mut before, &mut prev);
+        std::mem::swap(&mut prev, &mut curr);
+    }
+    prev[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{edit_distance, RouteNotFound};
+
+    #[test]
+    fn distance() {
+        assert_eq!(edit_distance("", ""), 0);
+        assert_eq!(edit_distance("users", ""), 5);
+        assert_eq!(edit_distance("users", "users"), 0);
+        assert_eq!(edit_distance("usres", "users"), 1);
+        assert_eq!(edit_distance("ca", "abc"), 3);
+        assert_eq!(edit_distance("ui.othr", "ui.other"), 1);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+    }
+
+    #[test]
+    fn suggestions() {
+        let names = [
+            "users.show",
+            "users.index",
+            "users.shows",
+            "posts.show",
+            "index",
+        ];
+        let err = RouteNotFound::new("users.shw", &names, ".");
+        assert_eq!(err.suggestions, ["users.show", "users.shows"]);
+        assert_eq!(
+            err.to_string(),
+            "Route `users.shw` does not exist, did you mean `users.show`, `users.shows`? \
+             (routes under `users`: `users.index`, `users.show`, `users.shows`)"
+        );
+
+        let err = RouteNotFound::new("usres.show", &names, ".");
+        assert_eq!(err.suggestions, ["users.show", "users.shows"]);
+        let err = RouteNotFound::new("idnex", &names, ".");
+        assert_eq!(err.suggestions, ["index"]);
+
+        let err = RouteNotFound::new("login", &names, ".");
+        assert!(err.suggestions.is_empty());
+        assert_eq!(err.prefix, None);
+        assert_eq!(err.to_string(), "Route `login` does not exist");
+    }
+
+    #[test]
+    fn prefix_routes() {
+        let names = ["ui.index", "ui.other", "ui.admin.users", "users.show"];
+        let err = RouteNotFound::new("ui.admin.othr", &names, ".");
+        assert_eq!(err.prefix.as_deref(), Some("ui.admin"));
+        assert_eq!(err.prefix_routes, ["ui.admin.users"]);
+
+        let err = RouteNotFound::new("ui.missing.page", &names, ".");
+        assert_eq!(err.prefix.as_deref(), Some("ui"));
+        assert_eq!(
+            err.to_string(),
+            "Route `ui.missing.page` does not exist (routes under `ui`: `ui.admin.users`, `ui.index`, `ui.other`)"
+        );
+
+        let err = RouteNotFound::new("ui.othr", &names, ".");
+        assert_eq!(
+            err.to_string(),
+            "Route `ui.othr` does not exist, did you mean `ui.other`? (routes under `ui`: `ui.admin.users`, `ui.index`, `ui.other`)"
+        );
+    }
+}
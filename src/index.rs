@@ -0,0 +1,88 @@
+//! Reverse lookup of route names by path
+//!
+//! This is built once when the [`Routes`](crate::Routes) are created
+
+use std::{collections::HashMap, fmt};
+
+use percent_encoding::percent_decode_str;
+
+use crate::{Params, RouteInfo};
+
+/// An index from route paths to the names of the routes with that path
+pub(crate) struct PathIndex {
+    /// Route names by path, there can be multiple names with different methods on one path
+    by_path: HashMap<String, Vec<crate::String>>,
+    /// Matches concrete paths to the path templates in `by_path`
+    matcher: matchit::Router<String>,
+    /// Matches concrete paths at or below the mount points of nested services to their names
+    mounts: matchit::Router<crate::String>,
+}
+
+impl PathIndex {
+    pub(crate) fn new(map: &HashMap<crate::String, RouteInfo>) -> Self {
+        let mut by_path: HashMap<_, Vec<crate::String>> = HashMap::new();
+        let mut mounts = matchit::Router::new();
+        for (name, info) in map {
+            by_path
+                .entry(info.path().to_string())
+                .or_default()
+                .push(name.clone());
+            if let Some(root) = info.mount_root() {
+                // a nested service also handles its mount point with and without a
+                // trailing slash, the wildcard only matches paths below it
+                let with_slash = format!("{}/", root.trim_end_matches('/'));
+                for path in [root.to_owned(), with_slash, info.path().to_string()] {
+                    let _ = mounts.insert(path, name.clone());
+                }
+            }
+        }
+        // Keep the order of names with the same path stable between runs
+        by_path.values_mut().for_each(|names| names.sort());
+
+        let mut matcher = matchit::Router::new();
+        for path in by_path.keys() {
+            // axum uses the same matcher so every path it accepted can be inserted,
+            // paths that were never registered with axum are only found by `names`
+            let _ = matcher.insert(path.clone(), path.clone());
+        }
+        Self {
+            by_path,
+            matcher,
+            mounts,
+        }
+    }
+
+    /// The names of the routes with exactly this path template
+    pub(crate) fn names(&self, path: &str) -> Option<&[crate::String]> {
+        self.by_path.get(path).map(Vec::as_slice)
+    }
+
+    /// The names of the routes matching a concrete path and the decoded path params
+    ///
+    /// Paths that only match the mount point of a nested service return its name
+    pub(crate) fn at(&self, path: &str) -> Option<(&[crate::String], Params)> {
+        let Ok(matched) = self.matcher.at(path) else {
+            let name = self.mount_at(path)?;
+            return Some((std::slice::from_ref(name), Params::new()));
+        };
+        let params = matched
+            .params
+            .iter()
+            .map(|(key, value)| (key, percent_decode_str(value).decode_utf8_lossy()))
+            .collect();
+        Some((self.names(matched.value)?, params))
+    }
+
+    /// The name of the nested service whose mount point contains a concrete path
+    pub(crate) fn mount_at(&self, path: &str) -> Option<&crate::String> {
+        self.mounts.at(path).ok().map(|matched| matched.value)
+    }
+}
+
+impl fmt::Debug for PathIndex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PathIndex")
+            .field("by_path", &self.by_path)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,171 @@
+//! Information stored for each named route
+//!
+//! Check out [`RouteInfo`] for more information
+
+use axum::{
+    http::{Extensions, Method},
+    Router,
+};
+
+use crate::{routing::Methods, RoutePath};
+
+/// Everything known about a named route
+#[derive(Clone, Debug)]
+pub struct RouteInfo {
+    path: RoutePath,
+    methods: Methods,
+    extensions: Extensions,
+    mount: bool,
+}
+
+impl RouteInfo {
+    pub(crate) fn new(path: RoutePath, methods: Methods) -> Self {
+        Self {
+            path,
+            methods,
+            extensions: Extensions::new(),
+            mount: false,
+        }
+    }
+
+    /// The path of the route
+    pub fn path(&self) -> &RoutePath {
+        &self.path
+    }
+
+    /// The HTTP methods the route accepts
+    ///
+    /// These are only known when the route was built with [`routing`](crate::routing), see
+    /// [`Methods`] for more information.
+    pub fn methods(&self) -> &Methods {
+        &self.methods
+    }
+
+    /// Returns whether the route accepts `method`, `None` if its methods are
+    /// [`Unknown`](Methods::Unknown)
+    pub fn accepts(&self, method: &Method) -> Option<bool> {
+        self.methods.accepts(method)
+    }
+
+    /// The metadata attached to the route when it was registered
+    ///
+    /// Check out [`route_with_meta`](crate::NamedRouter::route_with_meta) for more information
+    pub fn extensions(&self) -> &Extensions {
+        &self.extensions
+    }
+
+    /// The metadata of type `T` attached to the route
+    pub fn meta<T: Send + Sync + 'static>(&self) -> Option<&T> {
+        self.extensions.get()
+    }
+
+    pub(crate) fn with_path(self, path: RoutePath) -> Self {
+        Self { path, ..self }
+    }
+
+    pub(crate) fn with_extensions(self, extensions: Extensions) -> Self {
+        Self { extensions, ..self }
+    }
+
+    /// Mark the route as the mount point of a nested service, its path ends in `/*path`
+    pub(crate) fn with_mount(self) -> Self {
+        Self {
+            mount: true,
+            ..self
+        }
+    }
+
+    /// The path of the mount point without the `/*path` wildcard, `None` if the route is
+    /// not a nested service
+    pub(crate) fn mount_root(&self) -> Option<&str> {
+        if !self.mount {
+            return None;
+        }
+        let root = self.path.as_str().strip_suffix("/*path")?;
+        Some(if root.is_empty() { "/" } else { root })
+    }
+}
+
+/// The wildcard axum adds below the mount point of a nested service
+const NEST_TAIL: &str = "/*__private__axum_nest_tail_param";
+
+/// The routes of a plain axum [`Router`]
+#[derive(Debug, Default, PartialEq, Eq)]
+pub(crate) struct RouterPaths {
+    /// The paths of the routes in alphabetical order, this includes the mount points axum
+    /// adds routes for like `/files` and `/files/`
+    pub(crate) routes: Vec<String>,
+    /// The mount points of nested services in alphabetical order, like `/files`
+    pub(crate) mounts: Vec<String>,
+}
+
+impl RouterPaths {
+    /// Returns true if `path` is only a route because it is the mount point of a service
+    pub(crate) fn is_mount(&self, path: &str) -> bool {
+        let path = path.strip_suffix('/').unwrap_or(path);
+        self.mounts.iter().any(|mount| mount == path)
+    }
+}
+
+/// The paths of the routes in a plain axum [`Router`]
+///
+/// Returns `None` if the routes could not be read from the router
+pub(crate) fn router_paths<S>(router: &Router<S>) -> Option<RouterPaths> {
+    // a `Router` only exposes its routes through its `Debug` output, a map from route ids
+    // to endpoints followed by a map from route ids to paths
+    let debug = format!("{router:?}");
+    let rest = debug.strip_prefix("Router { path_router: PathRouter { routes: {")?;
+    let (_, rest) = rest.split_once("}, node: Node { paths: {")?;
+    let (paths, _) = rest.split_once("} }")?;
+
+    let mut found = RouterPaths::default();
+    for entry in paths.split("RouteId(").skip(1) {
+        let (_, path) = entry.split_once("): \"")?;
+        let (path, _) = path.split_once('"')?;
+        if let Some(mount) = path.strip_suffix(NEST_TAIL) {
+            found.mounts.push(mount.to_owned());
+        } else if !path.contains("__private__axum") {
+            // other paths axum adds, like for fallbacks, are not routes of their own
+            found.routes.push(path.to_owned());
+        }
+    }
+    found.routes.sort_unstable();
+    found.mounts.sort_unstable();
+    Some(found)
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        routing::{any, get},
+        Router,
+    };
+
+    use super::{router_paths, RouterPaths};
+
+    async fn dummy() {}
+
+    #[test]
+    fn router_paths_from_debug() {
+        // fails when axum changes its `Debug` output so it is noticed before a release
+        let router: Router = Router::new()
+            .route("/", get(dummy))
+            .route("/users/:id", get(dummy).post(dummy))
+            .nest_service("/files", any(dummy));
+
+        let paths = router_paths(&router).unwrap();
+        assert_eq!(
+            paths,
+            RouterPaths {
+                routes: vec![
+                    "/".into(),
+                    "/files".into(),
+                    "/files/".into(),
+                    "/users/:id".into()
+                ],
+                mounts: vec!["/files".into()],
+            }
+        );
+        assert!(paths.is_mount("/files/") && !paths.is_mount("/users/:id"));
+    }
+}
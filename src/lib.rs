@@ -7,23 +7,63 @@
 //!
 //! Check out [`NamedRouter`] and [`Routes`] for more information on how this works
 
-use std::{collections::HashMap, convert::Infallible, path::{PathBuf, Path}, sync::Arc, task::Poll};
+use std::{
+    collections::{BTreeSet, HashMap},
+    convert::Infallible,
+    sync::Arc,
+    task::Poll,
+};
 
 use axum::{
-    body::{BoxBody, HttpBody},
-    extract::{
-        connect_info::IntoMakeServiceWithConnectInfo, rejection::ExtensionRejection, FromRequestParts,
-    },
-    http::Request,
+    body::HttpBody,
+    extract::{rejection::ExtensionRejection, FromRequestParts, Request},
+    http::{Extensions, Method},
     response::{Response, IntoResponse},
-    routing::{future::RouteFuture, IntoMakeService, Route, MethodRouter},
+    routing::{future::RouteFuture, IntoMakeService, Route},
     Extension, handler::Handler,
 };
-use futures::{future::BoxFuture, FutureExt, TryFutureExt};
 use tower_layer::Layer;
 use tower_service::Service;
 
-type ServiceResp = Response<BoxBody>;
+pub use absolute::{AbsoluteRoutes, AbsoluteRoutesRejection, ProxyPolicy};
+pub use current::{CurrentRoute, CurrentRouteRejection};
+pub use error::{NamedRouterError, RouteNotFound};
+pub use info::RouteInfo;
+pub use listing::{RouteListing, SortBy};
+pub use redirect::NamedRedirect;
+pub use template::UrlFor;
+use index::PathIndex;
+use routing::{MethodRoutes, Methods};
+pub use path::{InvalidRoutePath, RoutePath, Segment};
+pub use typed::{NamedRoutes, RouteDef};
+pub use url::{Params, UrlError};
+
+#[cfg(feature = "macros")]
+pub use axum_named_routes_macros::{named_routes, NamedRoutes};
+#[doc(hidden)]
+pub use typed::__private;
+
+mod absolute;
+mod current;
+mod error;
+mod index;
+mod info;
+mod listing;
+#[cfg(feature = "manifest")]
+pub mod manifest;
+#[cfg(feature = "openapi")]
+pub mod openapi;
+mod path;
+mod redirect;
+pub mod routing;
+pub mod sitemap;
+mod template;
+mod typed;
+#[cfg(feature = "manifest")]
+mod typescript;
+mod url;
+
+type ServiceResp = Response;
 type ServiceErr = Infallible;
 type String = std::borrow::Cow<'static, str>;
 
@@ -32,72 +72,303 @@ type String = std::borrow::Cow<'static, str>;
 /// It is also based on an [`Arc`](std::sync::Arc) internally so it can be cloned across requests
 /// efficiently.
 #[derive(Clone, Debug)]
-pub struct Routes(Arc<HashMap<String, PathBuf>>);
+pub struct Routes(Arc<RoutesInner>);
+
+#[derive(Debug)]
+struct RoutesInner {
+    map: HashMap<String, RouteInfo>,
+    index: PathIndex,
+    separator: String,
+    base_url: Option<std::string::String>,
+    proxy_policy: ProxyPolicy,
+}
+
+impl RoutesInner {
+    fn new(
+        map: HashMap<String, RouteInfo>,
+        separator: String,
+        base_url: Option<std::string::String>,
+        proxy_policy: ProxyPolicy,
+    ) -> Self {
+        Self {
+            index: PathIndex::new(&map),
+            map,
+            separator,
+            base_url,
+            proxy_policy,
+        }
+    }
+}
 
 impl Routes {
     /// Returns the route for the given name
     /// # Panics
-    /// Panics if the name does not exist in routes
-    pub fn has(&self, name: &str) -> &PathBuf {
-        match self.0.get(name) {
-            Some(path) => path,
-            None => panic!("called `Routes::has` for a route that does not exist"),
+    /// Panics if the name does not exist in routes, the message lists similar names
+    /// like [`RouteNotFound`]
+    pub fn has(&self, name: &str) -> &RoutePath {
+        match self.try_get(name) {
+            Ok(path) => path,
+            Err(err) => panic!("called `Routes::has` for a route that does not exist: {err}"),
         }
     }
 
     /// Tries to get the route for the given name
     /// if the route does not exist returns `None`
-    pub fn get(&self, name: &str) -> Option<&PathBuf> {
-        self.0.get(name)
+    pub fn get(&self, name: &str) -> Option<&RoutePath> {
+        self.0.map.get(name).map(RouteInfo::path)
+    }
+
+    /// Tries to get the route for the given name
+    /// if the route does not exist returns a [`RouteNotFound`] error with similar names
+    ///
+    /// The error implements [`IntoResponse`](axum::response::IntoResponse) so it can be
+    /// returned from handlers with `?`.
+    /// ```
+    /// use axum::routing::get;
+    /// use axum_named_routes::{NamedRouter, RouteNotFound, Routes};
+    ///
+    /// async fn index(routes: Routes) -> Result<String, RouteNotFound> {
+    ///     Ok(routes.try_get("index")?.to_string())
+    /// }
+    ///
+    /// let app: NamedRouter = NamedRouter::new()
+    ///     .route("index", "/", get(index));
+    /// let (_router, routes) = app.into_parts();
+    ///
+    /// let err = routes.try_get("indx").unwrap_err();
+    /// assert_eq!(err.suggestions, ["index"]);
+    /// ```
+    pub fn try_get(&self, name: &str) -> Result<&RoutePath, RouteNotFound> {
+        self.get(name).ok_or_else(|| {
+            let names: Vec<&str> = self.0.map.keys().map(AsRef::as_ref).collect();
+            RouteNotFound::new(name, &names, &self.0.separator)
+        })
     }
 
     /// Tries to get the route for the given name and takes an error
     /// to return if it does not exist
-    pub fn get_or<E>(&self, name: &str, err: E) -> Result<&PathBuf, E> {
-        self.0.get(name).ok_or(err)
+    pub fn get_or<E>(&self, name: &str, err: E) -> Result<&RoutePath, E> {
+        self.get(name).ok_or(err)
     }
 
     /// Tries to get the route for the given name and takes an `FnOnce`
     /// to create an error if it does not exist
-    pub fn get_or_else<F, E>(&self, name: &str, f: F) -> Result<&PathBuf, E>
+    pub fn get_or_else<F, E>(&self, name: &str, f: F) -> Result<&RoutePath, E>
     where
         F: FnOnce() -> E,
     {
-        self.0.get(name).ok_or_else(f)
+        self.get(name).ok_or_else(f)
+    }
+
+    /// Tries to get all information about the route for the given name
+    /// if the route does not exist returns `None`
+    pub fn info(&self, name: &str) -> Option<&RouteInfo> {
+        self.0.map.get(name)
+    }
+
+    /// Tries to get the metadata of type `T` attached to the route for the given name
+    ///
+    /// Returns `None` if the route does not exist or has no metadata of type `T`
+    pub fn meta<T: Send + Sync + 'static>(&self, name: &str) -> Option<&T> {
+        self.info(name)?.meta()
+    }
+
+    /// Iterate over the names and information of all routes in no particular order
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &RouteInfo)> {
+        self.0.map.iter().map(|(name, info)| (name.as_ref(), info))
     }
 
     /// Find name by path
     ///
-    /// This is a linear seach of the values within the map
-    pub fn find(&self, path: impl AsRef<Path>) -> Option<&str> {
-        let path = path.as_ref();
-        for (k, v) in self.0.iter() {
-            if v == path {
-                return Some(k.as_ref());
-            }
-        }
-        None
+    /// The path is the route path template like `/users/:id`, use
+    /// [`find_match`](Routes::find_match) to find the route of a concrete path like `/users/4`.
+    /// If multiple routes share the path the first name in alphabetical order is returned.
+    /// This is a lookup in an index built when the routes are created.
+    pub fn find(&self, path: impl AsRef<str>) -> Option<&str> {
+        let names = self.0.index.names(path.as_ref())?;
+        names.first().map(AsRef::as_ref)
+    }
+
+    /// Find the name of the route matching a concrete path and the params in the path
+    ///
+    /// This matches paths the same way axum does, the params are percent decoded.
+    /// If multiple routes share the path the first name in alphabetical order is returned.
+    /// ```
+    /// use axum::routing::get;
+    /// use axum_named_routes::NamedRouter;
+    ///
+    /// let app: NamedRouter = NamedRouter::new()
+    ///     .route("user", "/users/:id", get(|| async {}));
+    /// let (_router, routes) = app.into_parts();
+    ///
+    /// let (name, params) = routes.find_match("/users/4").unwrap();
+    /// assert_eq!(name, "user");
+    /// assert_eq!(params.get("id"), Some("4"));
+    /// assert_eq!(routes.url_for(name, params).unwrap(), "/users/4");
+    /// ```
+    pub fn find_match(&self, path: &str) -> Option<(&str, Params)> {
+        let (names, params) = self.0.index.at(path)?;
+        Some((names.first()?.as_ref(), params))
+    }
+
+    /// Generate a URL for the route with the given name by filling in its path parameters
+    ///
+    /// `params` can be any [`Serialize`](serde::Serialize) struct or map, a sequence of
+    /// key value pairs, or a [`Params`]. Each `:param` and `*wildcard` in the route path is
+    /// replaced by the percent encoded value with the same name.
+    /// ```
+    /// use axum::routing::get;
+    /// use axum_named_routes::{NamedRouter, Routes};
+    ///
+    /// async fn show_post(routes: Routes) -> String {
+    ///     routes.url_for("post", [("id", "4"), ("post_id", "hello world")]).unwrap()
+    ///     // == "/users/4/posts/hello%20world"
+    /// }
+    ///
+    /// let app: NamedRouter = NamedRouter::new()
+    ///     .route("post", "/users/:id/posts/:post_id", get(show_post));
+    /// ```
+    ///
+    /// Returns an error if the route does not exist or if there are missing or
+    /// unknown parameters.
+    pub fn url_for<P: serde::Serialize>(
+        &self,
+        name: &str,
+        params: P,
+    ) -> Result<std::string::String, UrlError> {
+        let path = self.try_get(name).map_err(UrlError::UnknownRoute)?;
+        let params = url::collect_params(params)?;
+        url::fill_path(path, params).strict(name)
+    }
+
+    /// Generate a URL for the route with the given name, appending any parameters that
+    /// are not used in the route path as a query string
+    ///
+    /// This works like [`url_for`](Routes::url_for) except that parameters which do not match
+    /// a `:param` or `*wildcard` in the route path are form urlencoded into the query string
+    /// instead of being an error.
+    /// ```
+    /// use axum::routing::get;
+    /// use axum_named_routes::{NamedRouter, Routes};
+    ///
+    /// async fn posts(routes: Routes) -> String {
+    ///     routes.url_for_query("posts", [("id", "4"), ("page", "2")]).unwrap()
+    ///     // == "/users/4/posts?page=2"
+    /// }
+    ///
+    /// let app: NamedRouter = NamedRouter::new()
+    ///     .route("posts", "/users/:id/posts", get(posts));
+    /// ```
+    pub fn url_for_query<P: serde::Serialize>(
+        &self,
+        name: &str,
+        params: P,
+    ) -> Result<std::string::String, UrlError> {
+        let path = self.try_get(name).map_err(UrlError::UnknownRoute)?;
+        let params = url::collect_params(params)?;
+        url::fill_path(path, params).with_query(name)
+    }
+
+    /// Generate a URL for a typed route
+    ///
+    /// The route is looked up by its name so this also works if it was registered with a
+    /// different path than it was declared with. Returns an error if the route was
+    /// never registered.
+    pub fn typed_url<R: NamedRoutes>(&self, route: &R) -> Result<std::string::String, UrlError> {
+        let name = route.route_def().name();
+        let path = self.try_get(name).map_err(UrlError::UnknownRoute)?;
+        url::fill_path(path, route.params().into_pairs()).strict(name)
+    }
+
+    /// The base URL configured with [`NamedRouter::base_url`]
+    pub fn base_url(&self) -> Option<&str> {
+        self.0.base_url.as_deref()
+    }
+
+    /// The same as [`url_for`](Routes::url_for) but prefixed with the configured
+    /// [`base_url`](NamedRouter::base_url)
+    ///
+    /// This is meant for generating URLs outside of a request, like in background jobs.
+    /// Inside of a handler [`AbsoluteRoutes`] can also use the host of the request.
+    pub fn absolute_url_for<P: serde::Serialize>(
+        &self,
+        name: &str,
+        params: P,
+    ) -> Result<std::string::String, UrlError> {
+        let base_url = self.base_url().ok_or(UrlError::NoBaseUrl)?;
+        Ok(base_url.to_owned() + &self.url_for(name, params)?)
+    }
+
+    /// The same as [`url_for_query`](Routes::url_for_query) but prefixed with the configured
+    /// [`base_url`](NamedRouter::base_url)
+    pub fn absolute_url_for_query<P: serde::Serialize>(
+        &self,
+        name: &str,
+        params: P,
+    ) -> Result<std::string::String, UrlError> {
+        let base_url = self.base_url().ok_or(UrlError::NoBaseUrl)?;
+        Ok(base_url.to_owned() + &self.url_for_query(name, params)?)
+    }
+
+    /// The separator used between the names of nested routers
+    pub fn separator(&self) -> &str {
+        &self.0.separator
+    }
+
+    pub(crate) fn proxy_policy(&self) -> &ProxyPolicy {
+        &self.0.proxy_policy
+    }
+
+    /// Find the name of the route with exactly this path template that accepts `method`
+    ///
+    /// Routes that are known to accept `method` are preferred over routes with unknown
+    /// methods, which can not be told apart and fall back to the first name.
+    pub(crate) fn name_for_match(&self, path: &str, method: &Method) -> Option<&str> {
+        let names = self.0.index.names(path)?;
+        let accepts = |accepted| {
+            names
+                .iter()
+                .find(|name| self.0.map[*name].accepts(method) == accepted)
+        };
+        accepts(Some(true))
+            .or_else(|| accepts(None))
+            .or_else(|| names.first())
+            .map(AsRef::as_ref)
+    }
+
+    /// The name of the nested service whose mount point contains a concrete path
+    pub(crate) fn mount_for(&self, path: &str) -> Option<&str> {
+        self.0.index.mount_at(path).map(AsRef::as_ref)
     }
 }
 
-impl Routes {
-    fn new(map: HashMap<String, PathBuf>) -> Self {
-        Self(Arc::new(map))
+impl FromIterator<RouteDef> for Routes {
+    /// Build routes directly from route definitions, like the `NAMED_ROUTES`
+    /// declared by `named_routes!`
+    fn from_iter<T: IntoIterator<Item = RouteDef>>(iter: T) -> Self {
+        let map = iter
+            .into_iter()
+            .map(|def| {
+                let path = RoutePath::from_router(def.path());
+                (def.name().into(), RouteInfo::new(path, Methods::Unknown))
+            })
+            .collect();
+        let inner = RoutesInner::new(map, ".".into(), None, ProxyPolicy::default());
+        Routes(Arc::new(inner))
     }
 }
 
+#[axum::async_trait]
 impl<S: Send + Sync> FromRequestParts<S> for Routes {
     type Rejection = ExtensionRejection;
 
-    fn from_request_parts<'life0,'life1,'async_trait>(parts: &'life0 mut axum::http::request::Parts, state: &'life1 S) -> BoxFuture<'async_trait, Result<Self, Self::Rejection>>
-    where 
-        'life0:'async_trait,
-        'life1:'async_trait,
-        Self:'async_trait
-    {
+    async fn from_request_parts(
+        parts: &mut axum::http::request::Parts,
+        state: &S,
+    ) -> Result<Self, Self::Rejection> {
         Extension::<Self>::from_request_parts(parts, state)
-            .map_ok(|ext| ext.0)
-            .boxed()
+            .await
+            .map(|ext| ext.0)
     }
 }
 
@@ -108,16 +379,18 @@ impl<S: Send + Sync> FromRequestParts<S> for Routes {
 /// when either [`into_make_service`](NamedRouter::into_make_service) or [`into_make_service_with_connect_info`](NamedRouter::into_make_service_with_connect_info)
 /// are called.
 #[derive(Debug)]
-pub struct NamedRouter<S = (), B = axum::body::Body> {
-    inner: axum::Router<S, B>,
-    routes: HashMap<String, PathBuf>,
+pub struct NamedRouter<S = ()> {
+    inner: axum::Router<S>,
+    routes: HashMap<String, RouteInfo>,
     nest_sep: String,
+    base_url: Option<std::string::String>,
+    proxy_policy: ProxyPolicy,
+    expected: BTreeSet<std::string::String>,
 }
 
-impl<S, B> NamedRouter<S, B>
+impl<S> NamedRouter<S>
 where
     S: Clone + Send + Sync + 'static,
-    B: HttpBody + Send + 'static,
 {
     /// Create a new NamedRouter with default values.
     /// The default name separator is `.`
@@ -139,11 +412,136 @@ where
         self
     }
 
+    /// Wrap a plain axum [`Router`](axum::Router) naming each of its routes after its path
+    ///
+    /// Names are derived with [`RoutePath::to_name`] using the default separator, so
+    /// `/users/:id/edit` is named `users.id.edit`. This allows adopting named routes
+    /// incrementally, routes added to the wrapped router later can be named explicitly.
+    /// ```
+    /// use axum::{routing::get, Router};
+    /// use axum_named_routes::NamedRouter;
+    ///
+    /// let legacy: Router = Router::new()
+    ///     .route("/", get(|| async {}))
+    ///     .route("/users/:id/edit", get(|| async {}));
+    /// let app = NamedRouter::wrap(legacy).route("about", "/about", get(|| async {}));
+    ///
+    /// assert_eq!(app.routes()["index"].path(), "/");
+    /// assert_eq!(app.routes()["users.id.edit"].path(), "/users/:id/edit");
+    /// ```
+    ///
+    /// Use [`From`] to wrap a router without naming any of its routes.
+    ///
+    /// # Panics
+    /// Panics if the routes of `router` can not be read or if two paths get the same name,
+    /// see [`try_wrap`](NamedRouter::try_wrap)
+    pub fn wrap(router: axum::Router<S>) -> Self {
+        unwrap_or_panic(Self::try_wrap(router))
+    }
+
+    /// The same as [`wrap`](NamedRouter::wrap) but returns an error instead of panicking
+    ///
+    /// axum does not expose the routes of a router so they are read from its `Debug`
+    /// output, if that fails an [`UnreadableRouter`](NamedRouterError::UnreadableRouter)
+    /// error is returned instead of a router without any named routes. Paths that get the
+    /// same name, like `/users/:id` and `/users/id`, are a
+    /// [`DuplicateName`](NamedRouterError::DuplicateName) error, use
+    /// [`try_wrap_with`](NamedRouter::try_wrap_with) to name them differently.
+    pub fn try_wrap(router: axum::Router<S>) -> Result<Self, NamedRouterError> {
+        Self::try_wrap_with(router, |path| Some(path.to_name(".")))
+    }
+
+    /// Wrap a plain axum [`Router`](axum::Router) naming its routes with `naming`
+    ///
+    /// `naming` is called with the path of each route and returns its name, or `None` to
+    /// leave the route unnamed. Services nested with
+    /// [`Router::nest_service`](axum::Router::nest_service) are named from their mount point
+    /// like `/files` and registered like [`nest_service`](NamedRouter::nest_service) with a
+    /// `*path` wildcard.
+    ///
+    /// # Panics
+    /// Panics if the routes of `router` can not be read or if two paths get the same name,
+    /// see [`try_wrap`](NamedRouter::try_wrap)
+    pub fn wrap_with<F>(router: axum::Router<S>, naming: F) -> Self
+    where
+        F: FnMut(&RoutePath) -> Option<std::string::String>,
+    {
+        unwrap_or_panic(Self::try_wrap_with(router, naming))
+    }
+
+    /// The same as [`wrap_with`](NamedRouter::wrap_with) but returns an error instead of
+    /// panicking when the routes of `router` can not be read or two paths get the same name
+    pub fn try_wrap_with<F>(
+        router: axum::Router<S>,
+        mut naming: F,
+    ) -> Result<Self, NamedRouterError>
+    where
+        F: FnMut(&RoutePath) -> Option<std::string::String>,
+    {
+        let existing = info::router_paths(&router).ok_or(NamedRouterError::UnreadableRouter)?;
+
+        let mut named = Self::from(router);
+        for mount in &existing.mounts {
+            let root = RoutePath::from_nest(mount);
+            let Some(name) = naming(&root) else {
+                continue;
+            };
+            let path = root.join(&RoutePath::from_router("/*path"));
+            named.check_name(&name, path.as_str())?;
+            let info = RouteInfo::new(path, Methods::Any).with_mount();
+            named.routes.insert(name.into(), info);
+        }
+        for path in &existing.routes {
+            // the routes axum adds at mount points are part of the mounted service
+            if existing.is_mount(path) {
+                continue;
+            }
+            let Ok(path) = RoutePath::parse(path) else {
+                continue;
+            };
+            if let Some(name) = naming(&path) {
+                named.check_name(&name, path.as_str())?;
+                let info = RouteInfo::new(path, Methods::Unknown);
+                named.routes.insert(name.into(), info);
+            }
+        }
+        Ok(named)
+    }
+
+    /// Set the base URL used for absolute URLs, like `https://example.com`
+    ///
+    /// When set this is used by [`Routes::absolute_url_for`] and takes precedence over
+    /// the request host in [`AbsoluteRoutes`]. It can include a path prefix if the
+    /// application is not served from the root.
+    ///
+    /// # Panics
+    /// Panics if `url` is not an absolute URL with a scheme and host
+    pub fn base_url<T: AsRef<str>>(mut self, url: T) -> Self {
+        let url = url.as_ref();
+        match url.parse::<axum::http::Uri>() {
+            Ok(uri)
+                if uri.scheme().is_some() && uri.authority().is_some() && uri.query().is_none() =>
+            {
+                self.base_url = Some(url.trim_end_matches('/').to_owned());
+            }
+            _ => panic!("base url `{url}` must be an absolute url with a scheme and host"),
+        }
+        self
+    }
+
+    /// Set which proxies are trusted to provide the scheme and host used by [`AbsoluteRoutes`]
+    ///
+    /// By default forwarding headers are ignored
+    pub fn trust_proxy(mut self, policy: ProxyPolicy) -> Self {
+        self.proxy_policy = policy;
+        self
+    }
+
     /// The same as [`Router::fallback`](axum::Router::fallback)
     #[inline]
     pub fn fallback<H, T>(mut self, handler: H) -> Self
     where
-        H: Handler<T, S, B>,
+        H: Handler<T, S>,
         T: 'static,
     {
         self.inner = self.inner.fallback(handler);
@@ -154,7 +552,7 @@ where
     #[inline]
     pub fn fallback_service<T>(mut self, service: T) -> Self
     where
-        T: Service<Request<B>, Error = ServiceErr> + Clone + Send + 'static,
+        T: Service<Request, Error = ServiceErr> + Clone + Send + 'static,
         T::Response: IntoResponse,
         T::Future: Send + 'static,
     {
@@ -164,32 +562,50 @@ where
 
     /// The same as [`Router::layer`](axum::Router::layer)
     #[inline]
-    pub fn layer<L, NewReqBody>(self, layer: L) -> NamedRouter<S, NewReqBody>
+    pub fn layer<L>(self, layer: L) -> NamedRouter<S>
     where
-        L: Layer<Route<B>> + Clone + Send + 'static,
-        L::Service: Service<Request<NewReqBody>> + Clone + Send + 'static,
-        <L::Service as Service<Request<NewReqBody>>>::Response: IntoResponse + 'static,
-        <L::Service as Service<Request<NewReqBody>>>::Error: Into<Infallible> + 'static,
-        <L::Service as Service<Request<NewReqBody>>>::Future: Send + 'static,
-        NewReqBody: HttpBody + 'static,
+        L: Layer<Route> + Clone + Send + 'static,
+        L::Service: Service<Request> + Clone + Send + 'static,
+        <L::Service as Service<Request>>::Response: IntoResponse + 'static,
+        <L::Service as Service<Request>>::Error: Into<Infallible> + 'static,
+        <L::Service as Service<Request>>::Future: Send + 'static,
     {
         let inner = self.inner.layer(layer);
         NamedRouter {
             inner,
             routes: self.routes,
             nest_sep: self.nest_sep,
+            base_url: self.base_url,
+            proxy_policy: self.proxy_policy,
+            expected: self.expected,
         }
     }
 
     /// The merges the inner axum [`Router`](axum::Router) and the route map on this router
-    pub fn merge<R>(mut self, other: R) -> Self
+    ///
+    /// # Panics
+    /// Panics if a route name in `other` is already used by this router
+    pub fn merge<R>(self, other: R) -> Self
+    where
+        R: Into<NamedRouter<S>>,
+    {
+        unwrap_or_panic(self.try_merge(other))
+    }
+
+    /// The same as [`merge`](NamedRouter::merge) but returns an error instead of panicking
+    /// when a route name in `other` is already used by this router
+    pub fn try_merge<R>(mut self, other: R) -> Result<Self, NamedRouterError>
     where
-        R: Into<NamedRouter<S, B>>,
+        R: Into<NamedRouter<S>>,
     {
         let other = other.into();
+        for (name, info) in &other.routes {
+            self.check_name(name, info.path().as_str())?;
+        }
         self.inner = self.inner.merge(other.inner);
         self.routes.extend(other.routes);
-        self
+        self.expected.extend(other.expected);
+        Ok(self)
     }
 
     /// Nests the inner axum [`Router`](axum::Router).
@@ -198,7 +614,6 @@ where
     /// The the name nesting process looks essentially like `name + separator + route_name`
     /// for example:
     /// ```
-    /// use std::path::PathBuf;
     /// use axum::routing::get;
     /// use axum_named_routes::{NamedRouter, Routes};
     ///
@@ -206,70 +621,431 @@ where
     ///     "Hello, World!"
     /// }
     ///
-    /// let ui_router: NamedRouter<(), axum::body::Body> = NamedRouter::new()
+    /// let ui_router: NamedRouter = NamedRouter::new()
     ///     .route("index", "/", get(index));
-    /// let base: NamedRouter<(), _> = NamedRouter::new()
+    /// let base: NamedRouter = NamedRouter::new()
     ///     .nest("ui", "/", ui_router)
     ///     .with_state(());
     ///
     /// let routes = base.routes();
     /// assert!(routes.get("ui.index").is_some());
-    /// assert_eq!(routes.get("ui.index").unwrap(), &PathBuf::from("/"));
+    /// assert_eq!(routes.get("ui.index").unwrap().path(), "/");
     ///
     /// base.into_make_service();
     /// ```
     ///
-    /// Also ensures all paths in `router` are joined to `path` uses
-    /// [`Path::join`](std::path::Path::join) like `path.join(route_path)`
-    pub fn nest<N, P, R>(mut self, name: N, path: P, router: R) -> Self
+    /// Also ensures all paths in `router` are joined to `path` using
+    /// [`RoutePath::join`] like `path.join(route_path)`
+    ///
+    /// # Panics
+    /// Panics if a prefixed route name from `router` is already used by this router
+    pub fn nest<N, P, R>(self, name: N, path: P, router: R) -> Self
     where
         N: Into<String>,
         P: AsRef<str>,
-        R: Into<NamedRouter<S, B>>,
+        R: Into<NamedRouter<S>>,
+    {
+        unwrap_or_panic(self.try_nest(name, path, router))
+    }
+
+    /// The same as [`nest`](NamedRouter::nest) but returns an error instead of panicking
+    /// when a prefixed route name from `router` is already used by this router
+    pub fn try_nest<N, P, R>(
+        mut self,
+        name: N,
+        path: P,
+        router: R,
+    ) -> Result<Self, NamedRouterError>
+    where
+        N: Into<String>,
+        P: AsRef<str>,
+        R: Into<NamedRouter<S>>,
     {
         let name = name.into();
         let router = router.into();
+        let prefix = RoutePath::from_nest(path.as_ref());
+
+        let prefixed_routes = router
+            .routes
+            .into_iter()
+            .map(|(inner_name, info)| {
+                let path = prefix.join(info.path());
+                (
+                    name.clone() + self.nest_sep.clone() + inner_name,
+                    info.with_path(path),
+                )
+            })
+            .collect::<Vec<_>>();
+        for (name, info) in &prefixed_routes {
+            self.check_name(name, info.path().as_str())?;
+        }
+
         self.inner = self.inner.nest(path.as_ref(), router.inner);
-        let path = PathBuf::from(path.as_ref());
-
-        let prefixed_routes = router.routes.into_iter().map(|(inner_name, inner_path)| {
-            // This is correct because axum routers panic when trying to insert a path that does
-            // not start with a "/" meaning inner_path is guaranteed to start with a "/" but that
-            // also means if we don't remove it then the path.join will fail to properly join the
-            // paths as it will think inner_path is an absolute path
-            let inner_path = inner_path.strip_prefix("/").unwrap();
-            (
-                name.clone() + self.nest_sep.clone() + inner_name,
-                path.join(inner_path),
-            )
-        });
         self.routes.extend(prefixed_routes);
+        self.expected.extend(router.expected);
+        Ok(self)
+    }
 
-        self
+    /// Nests a plain axum [`Router`](axum::Router) declaring the names of the routes in it
+    ///
+    /// This works like [`nest`](NamedRouter::nest) for routers that were not built with
+    /// a [`NamedRouter`], each declared `(route_name, route_path)` is prefixed the same way.
+    /// ```
+    /// use axum::{routing::get, Router};
+    /// use axum_named_routes::NamedRouter;
+    ///
+    /// let admin: Router = Router::new()
+    ///     .route("/", get(|| async {}))
+    ///     .route("/users", get(|| async {}));
+    /// let app: NamedRouter = NamedRouter::new()
+    ///     .nest_unnamed("admin", "/admin", admin, [("index", "/"), ("users", "/users")]);
+    ///
+    /// assert_eq!(app.routes()["admin.users"].path(), "/admin/users");
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if a prefixed route name is already used by this router or if a declared
+    /// path is not a route in `router`
+    pub fn nest_unnamed<N, P, I, RN, RP>(
+        self,
+        name: N,
+        path: P,
+        router: axum::Router<S>,
+        routes: I,
+    ) -> Self
+    where
+        N: Into<String>,
+        P: AsRef<str>,
+        I: IntoIterator<Item = (RN, RP)>,
+        RN: Into<String>,
+        RP: AsRef<str>,
+    {
+        unwrap_or_panic(self.try_nest_unnamed(name, path, router, routes))
+    }
+
+    /// The same as [`nest_unnamed`](NamedRouter::nest_unnamed) but returns an error instead
+    /// of panicking
+    ///
+    /// The declared paths are checked against the routes of `router` where possible, they
+    /// are read from its `Debug` output since axum does not expose them. If that fails the
+    /// declared paths are trusted and a warning is logged, use
+    /// [`try_nest_unnamed_checked`](NamedRouter::try_nest_unnamed_checked) to get an error
+    /// instead. The methods of the routes are not known.
+    pub fn try_nest_unnamed<N, P, I, RN, RP>(
+        self,
+        name: N,
+        path: P,
+        router: axum::Router<S>,
+        routes: I,
+    ) -> Result<Self, NamedRouterError>
+    where
+        N: Into<String>,
+        P: AsRef<str>,
+        I: IntoIterator<Item = (RN, RP)>,
+        RN: Into<String>,
+        RP: AsRef<str>,
+    {
+        self.nest_unnamed_inner(name.into(), path, router, routes, false)
+    }
+
+    /// The same as [`try_nest_unnamed`](NamedRouter::try_nest_unnamed) but returns an
+    /// [`UnreadableRouter`](NamedRouterError::UnreadableRouter) error instead of trusting
+    /// the declared paths when the routes of `router` can not be read
+    pub fn try_nest_unnamed_checked<N, P, I, RN, RP>(
+        self,
+        name: N,
+        path: P,
+        router: axum::Router<S>,
+        routes: I,
+    ) -> Result<Self, NamedRouterError>
+    where
+        N: Into<String>,
+        P: AsRef<str>,
+        I: IntoIterator<Item = (RN, RP)>,
+        RN: Into<String>,
+        RP: AsRef<str>,
+    {
+        self.nest_unnamed_inner(name.into(), path, router, routes, true)
+    }
+
+    fn nest_unnamed_inner<P, I, RN, RP>(
+        self,
+        name: String,
+        path: P,
+        router: axum::Router<S>,
+        routes: I,
+        checked: bool,
+    ) -> Result<Self, NamedRouterError>
+    where
+        P: AsRef<str>,
+        I: IntoIterator<Item = (RN, RP)>,
+        RN: Into<String>,
+        RP: AsRef<str>,
+    {
+        let existing = info::router_paths(&router);
+        if existing.is_none() {
+            if checked {
+                return Err(NamedRouterError::UnreadableRouter);
+            }
+            tracing::warn!(
+                nest = %name,
+                "the routes of the axum Router could not be read, trusting the declared paths"
+            );
+        }
+        let mut named = Self::from(router);
+        for (route_name, route_path) in routes {
+            let (route_name, route_path) = (route_name.into(), route_path.as_ref());
+            let unknown_path = || NamedRouterError::UnknownPath {
+                name: (name.clone() + self.nest_sep.clone() + route_name.clone()).into_owned(),
+                path: route_path.to_owned(),
+            };
+            if existing
+                .as_ref()
+                .is_some_and(|existing| !existing.routes.iter().any(|p| p == route_path))
+            {
+                return Err(unknown_path());
+            }
+            let parsed = RoutePath::parse(route_path).map_err(|_| unknown_path())?;
+            named.check_name(&route_name, route_path)?;
+            named
+                .routes
+                .insert(route_name, RouteInfo::new(parsed, Methods::Unknown));
+        }
+        self.try_nest(name, path, named)
+    }
+
+    /// The same as [`Router::nest_service`](axum::Router::nest_service) but the mount point
+    /// is registered with a name
+    ///
+    /// The route path is the mount point followed by a `*path` wildcard so URLs to
+    /// anything below the mount point can be generated:
+    /// ```
+    /// use axum::{http::StatusCode, routing::get_service};
+    /// use axum_named_routes::NamedRouter;
+    ///
+    /// let files = get_service(tower::service_fn(|_req| async { Ok(StatusCode::OK) }));
+    /// let (_, routes) = NamedRouter::<()>::new()
+    ///     .nest_service("static", "/static", files)
+    ///     .into_parts();
+    ///
+    /// assert_eq!(routes.get("static").unwrap(), "/static/*path");
+    /// assert_eq!(
+    ///     routes.url_for("static", [("path", "css/app.css")]).unwrap(),
+    ///     "/static/css/app.css"
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if `name` is already used by another route
+    pub fn nest_service<N, P, T>(self, name: N, path: P, service: T) -> Self
+    where
+        N: Into<String>,
+        P: AsRef<str>,
+        T: Service<Request, Error = ServiceErr> + Clone + Send + 'static,
+        T::Response: IntoResponse,
+        T::Future: Send + 'static,
+    {
+        unwrap_or_panic(self.try_nest_service(name, path, service))
+    }
+
+    /// The same as [`nest_service`](NamedRouter::nest_service) but returns an error instead
+    /// of panicking when `name` is already used by another route
+    pub fn try_nest_service<N, P, T>(
+        mut self,
+        name: N,
+        path: P,
+        service: T,
+    ) -> Result<Self, NamedRouterError>
+    where
+        N: Into<String>,
+        P: AsRef<str>,
+        T: Service<Request, Error = ServiceErr> + Clone + Send + 'static,
+        T::Response: IntoResponse,
+        T::Future: Send + 'static,
+    {
+        let name = name.into();
+        let mount = RoutePath::from_nest(path.as_ref()).join(&RoutePath::from_router("/*path"));
+        self.check_name(&name, mount.as_str())?;
+
+        self.inner = self.inner.nest_service(path.as_ref(), service);
+        self.routes
+            .insert(name, RouteInfo::new(mount, Methods::Any).with_mount());
+        Ok(self)
     }
 
     /// Add a service the the router with a name and a path
     /// the name can then later be used to get a reference to the path
-    pub fn route<N, P>(mut self, name: N, path: P, method_router: MethodRouter<S, B>) -> Self
+    ///
+    /// The methods of the route are recorded in its [`RouteInfo`] when `method_router` is
+    /// built with [`routing`], they are unknown for axum's [`MethodRouter`](axum::routing::MethodRouter).
+    ///
+    /// # Panics
+    /// Panics if `name` is already used by another route
+    pub fn route<N, P, R>(self, name: N, path: P, method_router: R) -> Self
+    where
+        N: Into<String>,
+        P: AsRef<str>,
+        R: Into<MethodRoutes<S>>,
+    {
+        unwrap_or_panic(self.try_route(name, path, method_router))
+    }
+
+    /// The same as [`route`](NamedRouter::route) but returns an error instead of panicking
+    /// when `name` is already used by another route
+    pub fn try_route<N, P, R>(
+        self,
+        name: N,
+        path: P,
+        method_router: R,
+    ) -> Result<Self, NamedRouterError>
+    where
+        N: Into<String>,
+        P: AsRef<str>,
+        R: Into<MethodRoutes<S>>,
+    {
+        self.try_route_with_meta(name, path, method_router, Extensions::new())
+    }
+
+    /// The same as [`route`](NamedRouter::route) but also attaches typed metadata to the route
+    ///
+    /// The metadata is stored in the [`RouteInfo`] of the route so it can be read by name
+    /// from the [`Routes`], like to build menus or check permissions.
+    /// ```
+    /// use axum::{http::Extensions, routing::get};
+    /// use axum_named_routes::NamedRouter;
+    ///
+    /// #[derive(Clone)]
+    /// struct Title(&'static str);
+    ///
+    /// let mut meta = Extensions::new();
+    /// meta.insert(Title("Users"));
+    ///
+    /// let app: NamedRouter = NamedRouter::new()
+    ///     .route_with_meta("users", "/users", get(|| async {}), meta);
+    /// let (_, routes) = app.into_parts();
+    ///
+    /// assert_eq!(routes.meta::<Title>("users").unwrap().0, "Users");
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if `name` is already used by another route
+    pub fn route_with_meta<N, P, R>(
+        self,
+        name: N,
+        path: P,
+        method_router: R,
+        meta: Extensions,
+    ) -> Self
+    where
+        N: Into<String>,
+        P: AsRef<str>,
+        R: Into<MethodRoutes<S>>,
+    {
+        unwrap_or_panic(self.try_route_with_meta(name, path, method_router, meta))
+    }
+
+    /// The same as [`route_with_meta`](NamedRouter::route_with_meta) but returns an error
+    /// instead of panicking when `name` is already used by another route
+    pub fn try_route_with_meta<N, P, R>(
+        mut self,
+        name: N,
+        path: P,
+        method_router: R,
+        meta: Extensions,
+    ) -> Result<Self, NamedRouterError>
     where
         N: Into<String>,
         P: AsRef<str>,
+        R: Into<MethodRoutes<S>>,
     {
+        let name = name.into();
+        self.check_name(&name, path.as_ref())?;
+        let (method_router, methods) = method_router.into().into_parts();
         self.inner = self.inner.route(path.as_ref(), method_router);
-        self.routes
-            .insert(name.into(), PathBuf::from(path.as_ref()));
+        let info = RouteInfo::new(RoutePath::from_router(path.as_ref()), methods);
+        self.routes.insert(name, info.with_extensions(meta));
+        Ok(self)
+    }
+
+    /// Add a typed route to the router using the name and path of `def`
+    ///
+    /// # Panics
+    /// Panics if the name is already used by another route
+    pub fn typed_route<R>(self, def: RouteDef, method_router: R) -> Self
+    where
+        R: Into<MethodRoutes<S>>,
+    {
+        self.route(def.name(), def.path(), method_router)
+    }
+
+    /// Declare route names that handlers or templates will look up
+    ///
+    /// The names are full names as used with [`Routes`], they are not prefixed when this
+    /// router is nested. [`into_router`](NamedRouter::into_router) panics if any of them
+    /// is not a route, use [`validate`](NamedRouter::validate) to check them without
+    /// panicking.
+    /// ```
+    /// use axum::routing::get;
+    /// use axum_named_routes::NamedRouter;
+    ///
+    /// let users: NamedRouter = NamedRouter::new()
+    ///     .route("show", "/:id", get(|| async {}))
+    ///     .expect_routes(["users.show", "users.edit"]);
+    /// let app: NamedRouter = NamedRouter::new().nest("users", "/users", users);
+    ///
+    /// let err = app.validate().unwrap_err();
+    /// assert!(err.to_string().contains("`users.edit`"));
+    /// ```
+    pub fn expect_routes<I>(mut self, names: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        self.expected
+            .extend(names.into_iter().map(|name| name.as_ref().to_owned()));
         self
     }
 
+    /// Declare the names of route definitions as expected routes
+    ///
+    /// This works like [`expect_routes`](NamedRouter::expect_routes) for the `ROUTES` of a
+    /// [`NamedRoutes`] type or the `NAMED_ROUTES` declared by `named_routes!`, so every
+    /// typed route has to be registered.
+    #[cfg_attr(feature = "macros", doc = "```")]
+    #[cfg_attr(not(feature = "macros"), doc = "```ignore")]
+    /// use axum::routing::get;
+    /// use axum_named_routes::{NamedRouter, NamedRoutes};
+    ///
+    /// #[derive(NamedRoutes)]
+    /// enum AppRoute {
+    ///     #[route("/")]
+    ///     Index,
+    ///     #[route("/users/:id")]
+    ///     User { id: u64 },
+    /// }
+    ///
+    /// let app: NamedRouter = NamedRouter::new()
+    ///     .typed_route(AppRoute::INDEX, get(|| async {}))
+    ///     .expect_defs(AppRoute::ROUTES.iter().copied());
+    ///
+    /// assert!(app.validate().is_err());
+    /// ```
+    pub fn expect_defs<I>(self, defs: I) -> Self
+    where
+        I: IntoIterator<Item = RouteDef>,
+    {
+        self.expect_routes(defs.into_iter().map(|def| def.name()))
+    }
+
     /// The same as [`Router::route_layer`](axum::Router::route_layer)
     #[inline]
     pub fn route_layer<L>(mut self, layer: L) -> Self
     where
-        L: Layer<Route<B>> + Clone + Send + 'static,
-        L::Service: Service<Request<B>> + Clone + Send + 'static,
-        <L::Service as Service<Request<B>>>::Response: IntoResponse + 'static,
-        <L::Service as Service<Request<B>>>::Error: Into<Infallible> + 'static,
-        <L::Service as Service<Request<B>>>::Future: Send + 'static,
+        L: Layer<Route> + Clone + Send + 'static,
+        L::Service: Service<Request> + Clone + Send + 'static,
+        <L::Service as Service<Request>>::Response: IntoResponse + 'static,
+        <L::Service as Service<Request>>::Error: Into<Infallible> + 'static,
+        <L::Service as Service<Request>>::Future: Send + 'static,
     {
         self.inner = self.inner.route_layer(layer);
         self
@@ -277,49 +1053,171 @@ where
 
     /// Add a service the the router with a name and a path
     /// the name can then later be used to get a reference to the path
-    pub fn route_service<N, P, T>(mut self, name: N, path: P, service: T) -> Self
+    ///
+    /// # Panics
+    /// Panics if `name` is already used by another route
+    pub fn route_service<N, P, T>(self, name: N, path: P, service: T) -> Self
     where
         N: Into<String>,
         P: AsRef<str>,
-        T: Service<Request<B>, Error = ServiceErr> + Clone + Send + 'static,
+        T: Service<Request, Error = ServiceErr> + Clone + Send + 'static,
         T::Response: IntoResponse,
         T::Future: Send + 'static,
     {
-        self.inner = self.inner.route_service(path.as_ref(), service);
-        self.routes
-            .insert(name.into(), PathBuf::from(path.as_ref()));
-        self
+        unwrap_or_panic(self.try_route_service(name, path, service))
+    }
+
+    /// The same as [`route_service`](NamedRouter::route_service) but returns an error
+    /// instead of panicking when `name` is already used by another route
+    pub fn try_route_service<N, P, T>(
+        self,
+        name: N,
+        path: P,
+        service: T,
+    ) -> Result<Self, NamedRouterError>
+    where
+        N: Into<String>,
+        P: AsRef<str>,
+        T: Service<Request, Error = ServiceErr> + Clone + Send + 'static,
+        T::Response: IntoResponse,
+        T::Future: Send + 'static,
+    {
+        self.insert_service(name.into(), path.as_ref(), service, Methods::Any)
+    }
+
+    /// The same as [`route_service`](NamedRouter::route_service) but also records the HTTP
+    /// methods the service handles in its [`RouteInfo`]
+    ///
+    /// The methods are only recorded, the service still receives requests of every method.
+    ///
+    /// # Panics
+    /// Panics if `name` is already used by another route
+    pub fn route_service_with_methods<N, P, T, M>(
+        self,
+        name: N,
+        path: P,
+        service: T,
+        methods: M,
+    ) -> Self
+    where
+        N: Into<String>,
+        P: AsRef<str>,
+        T: Service<Request, Error = ServiceErr> + Clone + Send + 'static,
+        T::Response: IntoResponse,
+        T::Future: Send + 'static,
+        M: IntoIterator<Item = Method>,
+    {
+        let methods = Methods::Only(methods.into_iter().collect());
+        unwrap_or_panic(self.insert_service(name.into(), path.as_ref(), service, methods))
+    }
+
+    fn insert_service<T>(
+        mut self,
+        name: String,
+        path: &str,
+        service: T,
+        methods: Methods,
+    ) -> Result<Self, NamedRouterError>
+    where
+        T: Service<Request, Error = ServiceErr> + Clone + Send + 'static,
+        T::Response: IntoResponse,
+        T::Future: Send + 'static,
+    {
+        self.check_name(&name, path)?;
+        self.inner = self.inner.route_service(path, service);
+        let info = RouteInfo::new(RoutePath::from_router(path), methods);
+        self.routes.insert(name, info);
+        Ok(self)
     }
 
     /// The same as [`Router::with_state`](axum::Router::with_state)
-    pub fn with_state<S2>(self, state: S) -> NamedRouter<S2, B> {
+    pub fn with_state<S2>(self, state: S) -> NamedRouter<S2> {
         let inner = self.inner.with_state(state);
         NamedRouter {
             inner,
             routes: self.routes,
             nest_sep: self.nest_sep,
+            base_url: self.base_url,
+            proxy_policy: self.proxy_policy,
+            expected: self.expected,
         }
     }
 
     /// Get a reference to the routes mapping before turning it into a [`Routes`]
-    pub fn routes(&self) -> &HashMap<String, PathBuf> {
+    pub fn routes(&self) -> &HashMap<String, RouteInfo> {
         &self.routes
     }
 
+    /// Check that every name declared with [`expect_routes`](NamedRouter::expect_routes)
+    /// or [`expect_defs`](NamedRouter::expect_defs) is a route
+    ///
+    /// Returns an error listing every missing name with similar existing names.
+    pub fn validate(&self) -> Result<(), NamedRouterError> {
+        let names: Vec<&str> = self.routes.keys().map(AsRef::as_ref).collect();
+        let missing: Vec<RouteNotFound> = self
+            .expected
+            .iter()
+            .filter(|name| !self.routes.contains_key(name.as_str()))
+            .map(|name| RouteNotFound::new(name, &names, &self.nest_sep))
+            .collect();
+        if missing.is_empty() {
+            Ok(())
+        } else {
+            Err(NamedRouterError::MissingRoutes(missing))
+        }
+    }
+
     /// Convert into a [`Router`](axum::Router) after adding an [`Routes`] as an [`Extension`](axum::extract::Extension) layer
-    pub fn into_router(self) -> axum::Router<S, B> {
-        self.inner.layer(Extension(Routes::new(self.routes)))
+    ///
+    /// # Panics
+    /// Panics if an expected route does not exist, see [`validate`](NamedRouter::validate)
+    pub fn into_router(self) -> axum::Router<S> {
+        self.into_parts().0
+    }
+
+    /// The same as [`into_router`](NamedRouter::into_router) but also returns the [`Routes`]
+    ///
+    /// This is useful to keep a copy of the [`Routes`] for use outside of requests.
+    ///
+    /// # Panics
+    /// Panics if an expected route does not exist, see [`validate`](NamedRouter::validate)
+    pub fn into_parts(self) -> (axum::Router<S>, Routes) {
+        unwrap_or_panic(self.validate());
+        let inner = RoutesInner::new(self.routes, self.nest_sep, self.base_url, self.proxy_policy);
+        let routes = Routes(Arc::new(inner));
+        let resolve_routes = routes.clone();
+        let router = self
+            .inner
+            .layer(axum::middleware::map_response(move |res: Response| {
+                std::future::ready(redirect::resolve(&resolve_routes, res))
+            }))
+            .layer(Extension(routes.clone()));
+        (router, routes)
+    }
+
+    fn check_name(&self, name: &str, path: &str) -> Result<(), NamedRouterError> {
+        match self.routes.get(name) {
+            Some(existing) => Err(NamedRouterError::DuplicateName {
+                name: name.to_owned(),
+                existing: existing.path().to_string(),
+                new: path.to_owned(),
+            }),
+            None => Ok(()),
+        }
     }
 }
 
-impl<B> NamedRouter<(), B>
-where
-    B: HttpBody + Send + 'static,
-{
+fn unwrap_or_panic<T>(res: Result<T, NamedRouterError>) -> T {
+    match res {
+        Ok(val) => val,
+        Err(err) => panic!("{err}"),
+    }
+}
+
+impl NamedRouter<()> {
     /// Uses [`Router::into_make_service`](axum::Router::into_make_service) after
     /// adding an [`Extension<Routes>`](axum::extract::Extension) layer to the inner router
-    #[cfg(feature = "tokio")]
-    pub fn into_make_service(self) -> IntoMakeService<axum::Router<(), B>> {
+    pub fn into_make_service(self) -> IntoMakeService<axum::Router<()>> {
         let inner = self.into_router();
         inner.into_make_service()
     }
@@ -329,13 +1227,13 @@ where
     #[cfg(feature = "tokio")]
     pub fn into_make_service_with_connect_info<C>(
         self,
-    ) -> IntoMakeServiceWithConnectInfo<axum::Router<(), B>, C> {
+    ) -> axum::extract::connect_info::IntoMakeServiceWithConnectInfo<axum::Router<()>, C> {
         let inner = self.into_router();
         inner.into_make_service_with_connect_info()
     }
 }
 
-impl<S, B> Clone for NamedRouter<S, B>
+impl<S> Clone for NamedRouter<S>
 where
     S: Clone,
 {
@@ -344,17 +1242,21 @@ where
             inner: self.inner.clone(),
             routes: self.routes.clone(),
             nest_sep: self.nest_sep.clone(),
+            base_url: self.base_url.clone(),
+            proxy_policy: self.proxy_policy.clone(),
+            expected: self.expected.clone(),
         }
     }
 }
 
-impl<B> Service<Request<B>> for NamedRouter<(), B>
+impl<B> Service<axum::http::Request<B>> for NamedRouter<()>
 where
-    B: HttpBody + Send + 'static,
+    B: HttpBody<Data = axum::body::Bytes> + Send + 'static,
+    B::Error: Into<axum::BoxError>,
 {
     type Response = ServiceResp;
     type Error = ServiceErr;
-    type Future = RouteFuture<B, ServiceErr>;
+    type Future = RouteFuture<ServiceErr>;
 
     #[inline]
     fn poll_ready(
@@ -365,21 +1267,35 @@ where
     }
 
     #[inline]
-    fn call(&mut self, req: Request<B>) -> Self::Future {
+    fn call(&mut self, req: axum::http::Request<B>) -> Self::Future {
         self.inner.call(req)
     }
 }
 
-impl<S, B> Default for NamedRouter<S, B>
+impl<S> From<axum::Router<S>> for NamedRouter<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    fn from(router: axum::Router<S>) -> Self {
+        Self {
+            inner: router,
+            ..Default::default()
+        }
+    }
+}
+
+impl<S> Default for NamedRouter<S>
 where
     S: Clone + Send + Sync + 'static,
-    B: HttpBody + Send + 'static,
 {
     fn default() -> Self {
         Self {
             inner: axum::Router::new(),
             routes: HashMap::default(),
             nest_sep: ".".into(),
+            base_url: None,
+            proxy_policy: ProxyPolicy::default(),
+            expected: BTreeSet::new(),
         }
     }
 }
@@ -388,16 +1304,23 @@ where
 mod tests {
     #![allow(clippy::unwrap_used)]
 
-    use std::path::PathBuf;
+    use std::convert::Infallible;
 
-    use crate::{NamedRouter, Routes};
-    use axum::{routing::get, body::Body};
+    use crate::{
+        routing::{any, delete, get, post, Methods},
+        NamedRouter, NamedRouterError, Routes,
+    };
+    use axum::{
+        body::{to_bytes, Body},
+        http::{Extensions, Method, Request, Response},
+    };
+    use tower::ServiceExt;
 
     async fn dummy(_routes: Routes) {}
 
     #[test]
     fn nesting() {
-        let a = NamedRouter::<(), Body>::new().route("route_a", "/a", get(dummy));
+        let a = NamedRouter::<()>::new().route("route_a", "/a", get(dummy));
         let b = NamedRouter::new()
             .route("route_a", "/a", get(dummy))
             .route("route_b", "/b", get(dummy));
@@ -409,18 +1332,330 @@ mod tests {
             .nest("c", "/c", c);
         let routes = app.routes();
 
-        assert!(routes.get("a.route_a").unwrap() == &PathBuf::from("/a"));
-        assert!(routes.get("b.route_a").unwrap() == &PathBuf::from("/b/a"));
-        assert!(routes.get("b.route_b").unwrap() == &PathBuf::from("/b/b"));
-        assert!(routes.get("b.route_c").unwrap() == &PathBuf::from("/b/c"));
-        assert!(routes.get("c.route_c").unwrap() == &PathBuf::from("/c/c"));
+        assert!(routes.get("a.route_a").unwrap().path() == "/a");
+        assert!(routes.get("b.route_a").unwrap().path() == "/b/a");
+        assert!(routes.get("b.route_b").unwrap().path() == "/b/b");
+        assert!(routes.get("b.route_c").unwrap().path() == "/b/c");
+        assert!(routes.get("c.route_c").unwrap().path() == "/c/c");
     }
 
     #[test]
     #[should_panic]
     fn route_overlap() {
-        let a = NamedRouter::<(), Body>::new().route("route_a", "/a", get(dummy));
+        let a = NamedRouter::<()>::new().route("route_a", "/a", get(dummy));
         let b = NamedRouter::new().route("route_a", "/a", get(dummy));
         NamedRouter::new().nest("a", "/", a).nest("b", "/", b);
     }
+
+    #[test]
+    fn methods() {
+        let svc = tower::service_fn(|_req: Request<Body>| async {
+            Ok::<_, Infallible>(Response::new(Body::empty()))
+        });
+        let nested = NamedRouter::<()>::new().route("delete", "/", delete(dummy));
+        let app = NamedRouter::new()
+            .route("index", "/", get(dummy).post(dummy))
+            .route("any", "/any", any(dummy))
+            .route_service("svc", "/svc", svc)
+            .route_service_with_methods("svc_put", "/svc_put", svc, [Method::PUT])
+            .nest("users", "/users", nested);
+        let routes = app.into_parts().1;
+
+        let index = routes.info("index").unwrap();
+        assert_eq!(
+            index.methods(),
+            &Methods::Only(vec![Method::GET, Method::HEAD, Method::POST])
+        );
+        assert_eq!(index.accepts(&Method::DELETE), Some(false));
+        assert_eq!(routes.info("any").unwrap().methods(), &Methods::Any);
+        assert_eq!(routes.info("svc").unwrap().methods(), &Methods::Any);
+        assert_eq!(
+            routes.info("svc_put").unwrap().methods(),
+            &Methods::Only(vec![Method::PUT])
+        );
+        assert_eq!(
+            routes.info("users.delete").unwrap().methods(),
+            &Methods::Only(vec![Method::DELETE])
+        );
+        let unknown = NamedRouter::<()>::new()
+            .route("legacy", "/", axum::routing::get(dummy))
+            .into_parts()
+            .1;
+        assert_eq!(unknown.info("legacy").unwrap().methods(), &Methods::Unknown);
+        assert_eq!(unknown.info("legacy").unwrap().accepts(&Method::GET), None);
+    }
+
+    #[test]
+    fn nest_empty_prefix() {
+        let api = NamedRouter::<()>::new().route("index", "/", get(dummy));
+        let files = NamedRouter::<()>::new().route("show", "/files", get(dummy));
+        let app = NamedRouter::<()>::new()
+            .nest("api", "", api)
+            .nest("files", "", files);
+        assert_eq!(app.routes()["api.index"].path(), "/");
+        assert_eq!(app.routes()["files.show"].path(), "/files");
+
+        let app = NamedRouter::<()>::new().nest_service("assets", "", axum::routing::get(dummy));
+        assert_eq!(app.routes()["assets"].path(), "/*path");
+    }
+
+    #[tokio::test]
+    async fn nest_service() {
+        let svc = tower::service_fn(|req: Request<Body>| async move {
+            Ok::<_, Infallible>(Response::new(Body::from(req.uri().path().to_owned())))
+        });
+        let assets = NamedRouter::<()>::new().nest_service("static", "/static", svc);
+        let (app, routes) = NamedRouter::new()
+            .nest_service("root", "/files/", svc)
+            .nest("assets", "/assets", assets)
+            .into_parts();
+
+        assert_eq!(routes.get("root").unwrap(), "/files/*path");
+        assert_eq!(routes.get("assets.static").unwrap(), "/assets/static/*path");
+        assert_eq!(routes.info("root").unwrap().methods(), &Methods::Any);
+
+        let url = routes
+            .url_for("assets.static", [("path", "css/app.css")])
+            .unwrap();
+        assert_eq!(url, "/assets/static/css/app.css");
+        let req = Request::builder().uri(&url).body(Body::empty()).unwrap();
+        let body = app.oneshot(req).await.unwrap().into_body();
+        assert_eq!(to_bytes(body, usize::MAX).await.unwrap(), "/css/app.css");
+
+        let taken = NamedRouter::<()>::new()
+            .route("static", "/static", get(dummy))
+            .try_nest_service("static", "/static", svc);
+        assert!(matches!(taken, Err(NamedRouterError::DuplicateName { .. })));
+    }
+
+    #[test]
+    fn nest_unnamed() {
+        let admin = || {
+            axum::Router::new()
+                .route("/", axum::routing::get(dummy))
+                .route("/users/:id", axum::routing::get(dummy).delete(dummy))
+        };
+        let routes = NamedRouter::<()>::new()
+            .nest_unnamed(
+                "admin",
+                "/admin",
+                admin(),
+                [("index", "/"), ("user", "/users/:id")],
+            )
+            .into_parts()
+            .1;
+        assert_eq!(routes.get("admin.index").unwrap(), "/admin");
+        // the methods of routes in a plain axum router are not known
+        assert_eq!(
+            routes.info("admin.user").unwrap().methods(),
+            &Methods::Unknown
+        );
+
+        let unknown = NamedRouter::<()>::new().try_nest_unnamed(
+            "admin",
+            "/admin",
+            admin(),
+            [("users", "/users")],
+        );
+        assert_eq!(
+            unknown.unwrap_err(),
+            NamedRouterError::UnknownPath {
+                name: "admin.users".into(),
+                path: "/users".into(),
+            }
+        );
+
+        let checked = NamedRouter::<()>::new()
+            .try_nest_unnamed_checked("admin", "/admin", admin(), [("index", "/")])
+            .unwrap();
+        assert_eq!(checked.routes()["admin.index"].path(), "/admin");
+    }
+
+    #[test]
+    fn wrap() {
+        let legacy = || {
+            axum::Router::<()>::new()
+                .route("/", axum::routing::get(dummy))
+                .route("/users/:id", axum::routing::get(dummy).post(dummy))
+                .route("/users/:id/edit", axum::routing::get(dummy))
+                .nest_service("/files", axum::routing::get(dummy))
+        };
+
+        let plain = NamedRouter::from(legacy()).route("about", "/about", get(dummy));
+        assert_eq!(plain.routes().len(), 1);
+
+        let routes = NamedRouter::try_wrap(legacy()).unwrap().into_parts().1;
+        let mut names: Vec<_> = routes.iter().map(|(name, _)| name).collect();
+        names.sort_unstable();
+        assert_eq!(names, ["files", "index", "users.id", "users.id.edit"]);
+        assert_eq!(
+            routes.info("users.id").unwrap().methods(),
+            &Methods::Unknown
+        );
+
+        // nested services are registered as mount points
+        assert_eq!(routes.get("files").unwrap(), "/files/*path");
+        assert_eq!(
+            routes.url_for("files", [("path", "css/app.css")]).unwrap(),
+            "/files/css/app.css"
+        );
+        assert_eq!(routes.find_match("/files").unwrap().0, "files");
+        assert_eq!(routes.find_match("/files/css/app.css").unwrap().0, "files");
+
+        let routes = NamedRouter::wrap_with(legacy(), |path| {
+            path.as_str()
+                .starts_with("/users")
+                .then(|| path.to_name("_"))
+        })
+        .into_parts()
+        .1;
+        assert_eq!(routes.get("users_id_edit").unwrap(), "/users/:id/edit");
+        assert_eq!(routes.get("index"), None);
+
+        let colliding = axum::Router::<()>::new()
+            .route("/users/:id", axum::routing::get(dummy))
+            .route("/users/id", axum::routing::get(dummy));
+        assert_eq!(
+            NamedRouter::try_wrap(colliding).unwrap_err(),
+            NamedRouterError::DuplicateName {
+                name: "users.id".into(),
+                existing: "/users/:id".into(),
+                new: "/users/id".into(),
+            }
+        );
+    }
+
+    #[test]
+    fn route_meta() {
+        #[derive(Clone, Debug, PartialEq)]
+        struct Permission(&'static str);
+        #[derive(Clone)]
+        struct Deprecated;
+
+        let mut meta = Extensions::new();
+        meta.insert(Permission("admin"));
+        let admin = NamedRouter::<()>::new()
+            .route_with_meta("users", "/users", get(dummy), meta)
+            .route("index", "/", get(dummy));
+        let routes = NamedRouter::new()
+            .nest("admin", "/admin", admin)
+            .into_parts()
+            .1;
+
+        assert_eq!(
+            routes.meta::<Permission>("admin.users"),
+            Some(&Permission("admin"))
+        );
+        assert!(routes.meta::<Deprecated>("admin.users").is_none());
+        assert!(routes.meta::<Permission>("admin.index").is_none());
+        assert!(routes.meta::<Permission>("missing").is_none());
+    }
+
+    #[test]
+    fn reverse_lookup() {
+        let users = NamedRouter::<()>::new()
+            .route("show", "/:id", get(dummy))
+            .route("update", "/:id", post(dummy))
+            .route("files", "/:id/files/*path", get(dummy));
+        let routes = NamedRouter::new()
+            .route("index", "/", get(dummy))
+            .nest("users", "/users", users)
+            .into_parts()
+            .1;
+
+        assert_eq!(routes.find("/"), Some("index"));
+        assert_eq!(routes.find("/users/:id"), Some("users.show"));
+        assert_eq!(routes.find("/users/4"), None);
+
+        let (name, params) = routes.find_match("/users/4").unwrap();
+        assert_eq!(name, "users.show");
+        assert_eq!(params.get("id"), Some("4"));
+        let (name, params) = routes.find_match("/users/a%20b/files/css/app.css").unwrap();
+        assert_eq!(name, "users.files");
+        assert_eq!(
+            params.iter().collect::<Vec<_>>(),
+            [("id", "a b"), ("path", "css/app.css")]
+        );
+        assert!(routes.find_match("/missing").is_none());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Name `route_a` is already used for `/a` and cannot be used for `/b`"
+    )]
+    fn duplicate_name() {
+        NamedRouter::<()>::new()
+            .route("route_a", "/a", get(dummy))
+            .route("route_a", "/b", get(dummy));
+    }
+
+    #[test]
+    fn try_duplicate_name() {
+        let a = NamedRouter::<()>::new().route("route_a", "/a", get(dummy));
+        let b = NamedRouter::new().route("route_a", "/b", get(dummy));
+        let nested = NamedRouter::<()>::new().route("route_a", "/", get(dummy));
+
+        let err = a.clone().try_merge(b).unwrap_err();
+        assert_eq!(
+            err,
+            NamedRouterError::DuplicateName {
+                name: "route_a".into(),
+                existing: "/a".into(),
+                new: "/b".into(),
+            }
+        );
+
+        let err = a
+            .clone()
+            .try_route("route_a", "/c", get(dummy))
+            .unwrap_err();
+        assert!(matches!(err, NamedRouterError::DuplicateName { .. }));
+
+        let err = NamedRouter::new()
+            .route("n.route_a", "/a", get(dummy))
+            .try_nest("n", "/n", nested)
+            .unwrap_err();
+        assert_eq!(
+            err,
+            NamedRouterError::DuplicateName {
+                name: "n.route_a".into(),
+                existing: "/a".into(),
+                new: "/n".into(),
+            }
+        );
+
+        assert!(a.try_route("route_b", "/b", get(dummy)).is_ok());
+    }
+
+    #[test]
+    fn expected_routes() {
+        let users = NamedRouter::<()>::new()
+            .route("show", "/:id", get(dummy))
+            .expect_routes(["users.show", "users.shw"]);
+        let app = NamedRouter::new()
+            .route("index", "/", get(dummy))
+            .expect_routes(["index"])
+            .nest("users", "/users", users);
+
+        let err = app.validate().unwrap_err();
+        let NamedRouterError::MissingRoutes(missing) = &err else {
+            panic!("unexpected error {err}");
+        };
+        assert_eq!(missing.len(), 1);
+        assert_eq!(missing[0].name, "users.shw");
+        assert_eq!(missing[0].suggestions, ["users.show"]);
+
+        let app = app.route("users.shw", "/users/shw", get(dummy));
+        assert!(app.validate().is_ok());
+        let _ = app.into_router();
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected routes are missing:\n  Route `about` does not exist")]
+    fn expected_routes_into_router() {
+        let _ = NamedRouter::<()>::new()
+            .route("index", "/", get(dummy))
+            .expect_routes(["index", "about"])
+            .into_router();
+    }
 }
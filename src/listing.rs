@@ -0,0 +1,212 @@
+//! Human readable listings of the named routes
+//!
+//! Check out [`RouteListing`] for more information
+
+use std::fmt;
+
+use axum::http::{header, Uri};
+
+use crate::{
+    routing::{get, Methods},
+    NamedRouter, RouteInfo, Routes,
+};
+
+/// The order of the routes in a [`RouteListing`]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SortBy {
+    /// Sort by route name, nested routes are listed below the routes they are nested in
+    #[default]
+    Name,
+    /// Sort by path template
+    Path,
+}
+
+/// A table of the named routes, like `rails routes`
+///
+/// Each route is listed with its name, methods and path. Names are indented by how deep
+/// the route is nested, `ANY` is listed for routes that accept any method and `?` for
+/// routes with [`Unknown`](Methods::Unknown) methods.
+/// ```
+/// use axum::routing::get;
+/// use axum_named_routes::{NamedRouter, SortBy};
+///
+/// let users = NamedRouter::new().route("show", "/:id", get(|| async {}));
+/// let app: NamedRouter = NamedRouter::new()
+///     .route("index", "/", get(|| async {}))
+///     .nest("users", "/users", users);
+/// let (_, routes) = app.into_parts();
+///
+/// println!("{}", routes.listing(SortBy::Path));
+/// // NAME          METHODS   PATH
+/// // index         GET,HEAD  /
+/// //   users.show  GET,HEAD  /users/:id
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct RouteListing<'a> {
+    routes: &'a Routes,
+    sort: SortBy,
+}
+
+impl Routes {
+    /// A [`RouteListing`] of all routes sorted by `sort`
+    ///
+    /// The [`Display`](fmt::Display) implementation of [`Routes`] is the listing sorted by name.
+    pub fn listing(&self, sort: SortBy) -> RouteListing<'_> {
+        RouteListing { routes: self, sort }
+    }
+}
+
+impl fmt::Display for RouteListing<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let separator = self.routes.separator();
+        let mut rows: Vec<(String, String, &RouteInfo)> = self
+            .routes
+            .iter()
+            .map(|(name, info)| {
+                let depth = match separator {
+                    "" => 0,
+                    sep => name.matches(sep).count(),
+                };
+                let methods = match info.methods() {
+                    Methods::Only(methods) => methods
+                        .iter()
+                        .map(|method| method.as_str())
+                        .collect::<Vec<_>>()
+                        .join(","),
+                    Methods::Any => "ANY".to_owned(),
+                    Methods::Unknown => "?".to_owned(),
+                };
+                (format!("{}{name}", "  ".repeat(depth)), methods, info)
+            })
+            .collect();
+        match self.sort {
+            SortBy::Name => rows.sort_by(|a, b| a.0.trim_start().cmp(b.0.trim_start())),
+            SortBy::Path => rows.sort_by(|a, b| {
+                (a.2.path().as_str(), a.0.trim_start())
+                    .cmp(&(b.2.path().as_str(), b.0.trim_start()))
+            }),
+        }
+
+        let name_width = rows.iter().map(|row| row.0.len()).max().unwrap_or(0).max(4);
+        let methods_width = rows.iter().map(|row| row.1.len()).max().unwrap_or(0).max(7);
+        write!(
+            f,
+            "{:name_width$}  {:methods_width$}  PATH",
+            "NAME", "METHODS"
+        )?;
+        for (name, methods, info) in rows {
+            write!(
+                f,
+                "\n{name:name_width$}  {methods:methods_width$}  {}",
+                info.path()
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Routes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.listing(SortBy::Name).fmt(f)
+    }
+}
+
+impl<S> NamedRouter<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    /// Add a named `GET` route that serves the [`RouteListing`] of the final routes as text
+    ///
+    /// The routes are sorted by name, or by path with a `?sort=path` query. This is meant
+    /// for debugging so it should usually only be added in development. The route is left
+    /// out of the sitemap.
+    ///
+    /// # Panics
+    /// Panics if `name` is already used by another route
+    pub fn listing_route<N, P>(self, name: N, path: P) -> Self
+    where
+        N: Into<crate::String>,
+        P: AsRef<str>,
+    {
+        let handler = |routes: Routes, uri: Uri| async move {
+            let sort = form_urlencoded::parse(uri.query().unwrap_or_default().as_bytes())
+                .any(|(key, value)| key == "sort" && value == "path");
+            let sort = if sort { SortBy::Path } else { SortBy::Name };
+            let listing = routes.listing(sort).to_string();
+            (
+                [(header::CONTENT_TYPE, "text/plain; charset=utf-8")],
+                listing,
+            )
+        };
+        self.route_with_meta(name, path, get(handler), crate::sitemap::excluded())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use axum::{
+        body::{to_bytes, Body},
+        http::Request,
+    };
+    use tower::ServiceExt;
+
+    use crate::{
+        routing::{any, get},
+        NamedRouter, SortBy,
+    };
+
+    async fn dummy() {}
+
+    #[tokio::test]
+    async fn listing() {
+        let users = NamedRouter::new()
+            .route("show", "/:id", get(dummy).post(dummy))
+            .route("files", "/:id/files/*path", any(dummy));
+        let (app, routes) = NamedRouter::new()
+            .route("index", "/", get(dummy))
+            .route("legacy", "/legacy", axum::routing::get(dummy))
+            .nest("users", "/users", users)
+            .listing_route("routes", "/_routes")
+            .into_parts();
+
+        assert_eq!(
+            routes.to_string(),
+            [
+                "NAME           METHODS        PATH",
+                "index          GET,HEAD       /",
+                "legacy         ?              /legacy",
+                "routes         GET,HEAD       /_routes",
+                "  users.files  ANY            /users/:id/files/*path",
+                "  users.show   GET,HEAD,POST  /users/:id",
+            ]
+            .join("\n")
+        );
+
+        let req = Request::builder()
+            .uri("/_routes?sort=path")
+            .body(Body::empty())
+            .unwrap();
+        let body = app.oneshot(req).await.unwrap().into_body();
+        let body = to_bytes(body, usize::MAX).await.unwrap();
+        assert_eq!(body, routes.listing(SortBy::Path).to_string());
+        let paths: Vec<_> = std::str::from_utf8(&body)
+            .unwrap()
+            .lines()
+            .skip(1)
+            .map(|line| line.split_whitespace().last().unwrap())
+            .collect();
+        assert_eq!(
+            paths,
+            [
+                "/",
+                "/_routes",
+                "/legacy",
+                "/users/:id",
+                "/users/:id/files/*path"
+            ]
+        );
+    }
+}
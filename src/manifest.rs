@@ -0,0 +1,232 @@
+//! A serializable manifest of the named routes for frontend clients
+//!
+//! Check out [`Manifest`] for more information
+
+use std::collections::BTreeMap;
+
+use axum::{
+    http::{header, StatusCode},
+    response::IntoResponse,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    routing::{get, Methods},
+    NamedRouter, Routes,
+};
+
+/// The version of the manifest schema, it is increased when the schema changes
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// All named routes in a form that can be serialized and sent to a frontend
+///
+/// This allows frontends to generate URLs using the same route names as the backend.
+/// ```
+/// use axum::routing::get;
+/// use axum_named_routes::NamedRouter;
+///
+/// let app: NamedRouter = NamedRouter::new()
+///     .route("user", "/users/:id", get(|| async {}))
+///     .route("api.user", "/api/users/:id", get(|| async {}));
+/// let (_, routes) = app.into_parts();
+///
+/// let manifest = routes.manifest().only(["api.*"]);
+/// assert_eq!(manifest.routes["api.user"].params, ["id"]);
+/// assert!(!manifest.routes.contains_key("user"));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Manifest {
+    /// The [`SCHEMA_VERSION`] the manifest was created with
+    pub version: u32,
+    /// The configured [`base_url`](Routes::base_url)
+    pub base_url: Option<String>,
+    /// The [`separator`](Routes::separator) between the names of nested routes
+    pub separator: String,
+    /// The routes by name
+    pub routes: BTreeMap<String, ManifestRoute>,
+}
+
+/// A single route in a [`Manifest`]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct ManifestRoute {
+    /// The path template in axum syntax, like `/users/:id`
+    pub path: String,
+    /// The names of the params and wildcards in the path in order
+    pub params: Vec<String>,
+    /// The HTTP methods the route accepts, `["*"]` if it accepts any method and `None` if
+    /// the methods are [`Unknown`](Methods::Unknown)
+    pub methods: Option<Vec<String>>,
+}
+
+impl Manifest {
+    /// Keep only the routes with a name matching one of `patterns`
+    ///
+    /// A `*` in a pattern matches any sequence of characters, so `api.*` matches every
+    /// route nested under `api`.
+    pub fn only<I, P>(mut self, patterns: I) -> Self
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<str>,
+    {
+        let patterns: Vec<P> = patterns.into_iter().collect();
+        self.routes.retain(|name, _| {
+            patterns
+                .iter()
+                .any(|pattern| glob_match(pattern.as_ref(), name))
+        });
+        self
+    }
+}
+
+impl Routes {
+    /// Create a [`Manifest`] of all routes
+    pub fn manifest(&self) -> Manifest {
+        let routes = self
+            .iter()
+            .map(|(name, info)| {
+                let route = ManifestRoute {
+                    path: info.path().to_string(),
+                    params: info.path().params().map(str::to_owned).collect(),
+                    methods: match info.methods() {
+                        Methods::Only(methods) => {
+                            Some(methods.iter().map(ToString::to_string).collect())
+                        }
+                        Methods::Any => Some(vec!["*".to_owned()]),
+                        Methods::Unknown => None,
+                    },
+                };
+                (name.to_owned(), route)
+            })
+            .collect();
+        Manifest {
+            version: SCHEMA_VERSION,
+            base_url: self.base_url().map(str::to_owned),
+            separator: self.separator().to_owned(),
+            routes,
+        }
+    }
+}
+
+impl<S> NamedRouter<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    /// Add a named `GET` route that serves the [`Manifest`] of the final routes as JSON
+    ///
+    /// Only routes matching one of `patterns` are included, like with [`Manifest::only`].
+    /// Use `["*"]` to include every route. The route is left out of the sitemap.
+    ///
+    /// # Panics
+    /// Panics if `name` is already used by another route
+    pub fn manifest_route<N, P, I, T>(self, name: N, path: P, patterns: I) -> Self
+    where
+        N: Into<crate::String>,
+        P: AsRef<str>,
+        I: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        let patterns: Vec<String> = patterns.into_iter().map(Into::into).collect();
+        let handler = |routes: Routes| async move {
+            let manifest = routes.manifest().only(&patterns);
+            match serde_json::to_string(&manifest) {
+                Ok(json) => ([(header::CONTENT_TYPE, "application/json")], json).into_response(),
+                Err(err) => (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()).into_response(),
+            }
+        };
+        self.route_with_meta(name, path, get(handler), crate::sitemap::excluded())
+    }
+}
+
+/// Match `name` against a pattern where `*` matches any sequence of characters
+fn glob_match(pattern: &str, name: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let first = parts.next().unwrap_or_default();
+    let Some(mut rest) = name.strip_prefix(first) else {
+        return false;
+    };
+    let mut parts: Vec<&str> = parts.collect();
+    let Some(last) = parts.pop() else {
+        // there is no `*` in the pattern
+        return rest.is_empty();
+    };
+    for part in parts {
+        match rest.find(part) {
+            Some(i) => rest = &rest[i + part.len()..],
+            None => return false,
+        }
+    }
+    rest.ends_with(last)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use axum::{
+        body::{to_bytes, Body},
+        http::Request,
+    };
+    use tower::ServiceExt;
+
+    use super::glob_match;
+    use crate::{
+        manifest::{Manifest, SCHEMA_VERSION},
+        routing::{any, get, post},
+        NamedRouter,
+    };
+
+    async fn dummy() {}
+
+    #[test]
+    fn glob() {
+        assert!(glob_match("api.*", "api.users.show"));
+        assert!(glob_match("*", "index"));
+        assert!(glob_match("*.show", "api.users.show"));
+        assert!(glob_match("api.*.show", "api.users.show"));
+        assert!(glob_match("index", "index"));
+        assert!(!glob_match("index", "index.other"));
+        assert!(!glob_match("api.*", "admin.users"));
+        assert!(!glob_match("api.*.show", "api.show"));
+    }
+
+    #[tokio::test]
+    async fn manifest() {
+        let api = NamedRouter::new()
+            .route("user", "/users/:id", get(dummy).post(dummy))
+            .route("files", "/files/*path", any(dummy));
+        let (app, routes) = NamedRouter::new()
+            .base_url("https://example.com")
+            .route("login", "/login", post(dummy))
+            .nest("api", "/api", api)
+            .manifest_route("manifest", "/routes.json", ["api.*"])
+            .into_parts();
+
+        let manifest = routes.manifest();
+        assert_eq!(manifest.version, SCHEMA_VERSION);
+        assert_eq!(manifest.base_url.as_deref(), Some("https://example.com"));
+        assert_eq!(manifest.separator, ".");
+        assert_eq!(manifest.routes.len(), 4);
+        let user = &manifest.routes["api.user"];
+        assert_eq!(user.path, "/api/users/:id");
+        assert_eq!(user.params, ["id"]);
+        assert_eq!(
+            user.methods.as_deref(),
+            Some(&["GET".to_owned(), "HEAD".to_owned(), "POST".to_owned()][..])
+        );
+        assert_eq!(
+            manifest.routes["api.files"].methods,
+            Some(vec!["*".to_owned()])
+        );
+
+        let req = Request::builder()
+            .uri("/routes.json")
+            .body(Body::empty())
+            .unwrap();
+        let body = app.oneshot(req).await.unwrap().into_body();
+        let served: Manifest =
+            serde_json::from_slice(&to_bytes(body, usize::MAX).await.unwrap()).unwrap();
+        assert_eq!(served, manifest.only(["api.*"]));
+    }
+}
@@ -0,0 +1,299 @@
+//! OpenAPI documents generated from the named routes
+//!
+//! Check out [`Routes::openapi`] for more information
+
+use axum::http::{header, Method};
+use serde_json::{json, Map, Value};
+
+use crate::{
+    routing::{get, MethodRoutes, Methods},
+    NamedRouter, RouteInfo, RoutePath, Routes, Segment,
+};
+
+/// The methods that can be operations of an OpenAPI path item, `HEAD` is only documented
+/// for routes that do not accept `GET`
+const OPERATION_METHODS: [Method; 8] = [
+    Method::GET,
+    Method::PUT,
+    Method::POST,
+    Method::DELETE,
+    Method::OPTIONS,
+    Method::HEAD,
+    Method::PATCH,
+    Method::TRACE,
+];
+
+/// OpenAPI details of a named route, attached as metadata with
+/// [`route_with_meta`](NamedRouter::route_with_meta)
+/// ```
+/// use axum::{http::Extensions, routing::get};
+/// use axum_named_routes::{openapi::Operation, NamedRouter};
+///
+/// let mut meta = Extensions::new();
+/// meta.insert(Operation::new().summary("List users").tag("users"));
+///
+/// let app: NamedRouter = NamedRouter::new()
+///     .route_with_meta("users", "/users", get(|| async {}), meta);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Operation {
+    summary: Option<String>,
+    description: Option<String>,
+    tags: Vec<String>,
+    deprecated: bool,
+}
+
+impl Operation {
+    /// Create an operation without any details
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the short summary of the operation
+    pub fn summary<T: Into<String>>(mut self, summary: T) -> Self {
+        self.summary = Some(summary.into());
+        self
+    }
+
+    /// Set the description of the operation
+    pub fn description<T: Into<String>>(mut self, description: T) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Add a tag to the operation
+    pub fn tag<T: Into<String>>(mut self, tag: T) -> Self {
+        self.tags.push(tag.into());
+        self
+    }
+
+    /// Mark the operation as deprecated
+    pub fn deprecated(mut self) -> Self {
+        self.deprecated = true;
+        self
+    }
+}
+
+impl Routes {
+    /// Generate an OpenAPI 3.1 document with an operation for every named route
+    ///
+    /// The `operationId` of each operation is the route name, if a route accepts multiple
+    /// methods the lowercase method is appended like `users.post`. `HEAD` is left out for
+    /// routes that also accept `GET` and routes that accept any method have an operation for
+    /// every method OpenAPI supports. Routes with [`Unknown`](Methods::Unknown) methods, like
+    /// routes using axum's own method routers, are left out since their operations can not
+    /// be documented correctly. Summaries, descriptions and tags are taken from the
+    /// [`Operation`] metadata of the route.
+    /// ```
+    /// use axum_named_routes::{routing::get, NamedRouter};
+    ///
+    /// let app: NamedRouter = NamedRouter::new()
+    ///     .route("user", "/users/:id", get(|| async {}));
+    /// let (_, routes) = app.into_parts();
+    ///
+    /// let doc = routes.openapi("Example", "1.0.0");
+    /// assert_eq!(doc["paths"]["/users/{id}"]["get"]["operationId"], "user");
+    /// ```
+    pub fn openapi(&self, title: &str, version: &str) -> Value {
+        let mut routes: Vec<_> = self.iter().collect();
+        routes.sort_unstable_by_key(|(name, _)| *name);
+
+        let mut paths = Map::new();
+        for (name, info) in routes {
+            let methods = operation_methods(info);
+            if methods.is_empty() {
+                continue;
+            }
+            let item = paths
+                .entry(openapi_path(info.path()))
+                .or_insert_with(|| Value::Object(Map::new()));
+            for method in &methods {
+                let id = match methods.len() {
+                    1 => name.to_owned(),
+                    _ => format!("{name}.{}", method.as_str().to_lowercase()),
+                };
+                if let Value::Object(item) = item {
+                    item.entry(method.as_str().to_lowercase())
+                        .or_insert_with(|| operation(id, info));
+                }
+            }
+        }
+
+        json!({
+            "openapi": "3.1.0",
+            "info": { "title": title, "version": version },
+            "paths": paths,
+        })
+    }
+}
+
+impl<S> NamedRouter<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    /// Add a named `GET` route that serves the [`openapi`](Routes::openapi) document of
+    /// the final routes as JSON, the route is left out of the sitemap
+    ///
+    /// # Panics
+    /// Panics if `name` is already used by another route
+    pub fn openapi_route<N, P>(self, name: N, path: P, title: &str, version: &str) -> Self
+    where
+        N: Into<crate::String>,
+        P: AsRef<str>,
+    {
+        let handler = openapi_handler(title, version);
+        self.route_with_meta(name, path, handler, crate::sitemap::excluded())
+    }
+}
+
+fn openapi_handler<S>(title: &str, version: &str) -> MethodRoutes<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    let (title, version) = (title.to_owned(), version.to_owned());
+    get(|routes: Routes| async move {
+        let doc = routes.openapi(&title, &version);
+        (
+            [(header::CONTENT_TYPE, "application/json")],
+            doc.to_string(),
+        )
+    })
+}
+
+/// Convert an axum path template to an OpenAPI one, `/users/:id` becomes `/users/{id}`
+fn openapi_path(path: &RoutePath) -> String {
+    path.segments()
+        .iter()
+        .map(|segment| match segment {
+            Segment::Static(s) => format!("/{s}"),
+            Segment::Param(name) | Segment::Wildcard(name) => format!("/{{{name}}}"),
+        })
+        .collect()
+}
+
+/// The methods to document operations for, empty if the methods are unknown
+fn operation_methods(info: &RouteInfo) -> Vec<Method> {
+    let methods = match info.methods() {
+        Methods::Only(methods) => methods.as_slice(),
+        Methods::Any => &OPERATION_METHODS,
+        Methods::Unknown => return Vec::new(),
+    };
+    let has_get = methods.contains(&Method::GET);
+    methods
+        .iter()
+        .filter(|method| OPERATION_METHODS.contains(method))
+        .filter(|method| !(has_get && **method == Method::HEAD))
+        .cloned()
+        .collect()
+}
+
+fn operation(id: String, info: &RouteInfo) -> Value {
+    let parameters: Vec<Value> = info
+        .path()
+        .params()
+        .map(|name| {
+            json!({
+                "name": name,
+                "in": "path",
+                "required": true,
+                "schema": { "type": "string" },
+            })
+        })
+        .collect();
+
+    let mut operation = json!({
+        "operationId": id,
+        "responses": { "default": { "description": "Default response" } },
+    });
+    if !parameters.is_empty() {
+        operation["parameters"] = Value::Array(parameters);
+    }
+    if let Some(meta) = info.meta::<Operation>() {
+        if let Some(summary) = &meta.summary {
+            operation["summary"] = json!(summary);
+        }
+        if let Some(description) = &meta.description {
+            operation["description"] = json!(description);
+        }
+        if !meta.tags.is_empty() {
+            operation["tags"] = json!(meta.tags);
+        }
+        if meta.deprecated {
+            operation["deprecated"] = json!(true);
+        }
+    }
+    operation
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use axum::{
+        body::{to_bytes, Body},
+        http::{Extensions, Request},
+    };
+    use serde_json::{json, Value};
+    use tower::ServiceExt;
+
+    use crate::{
+        openapi::Operation,
+        routing::{any, get},
+        NamedRouter,
+    };
+
+    async fn dummy() {}
+
+    #[tokio::test]
+    async fn openapi() {
+        let mut meta = Extensions::new();
+        meta.insert(Operation::new().summary("Show a user").tag("users"));
+        let users = NamedRouter::new()
+            .route_with_meta("show", "/:id", get(dummy).delete(dummy), meta)
+            .route("files", "/:id/files/*path", any(dummy));
+        let (app, routes) = NamedRouter::new()
+            .route("index", "/", get(dummy))
+            .route("login", "/login", axum::routing::post(dummy))
+            .route("logout", "/logout", axum::routing::delete(dummy))
+            .nest("users", "/users", users)
+            .openapi_route("openapi", "/openapi.json", "Test", "1.0.0")
+            .into_parts();
+
+        let doc = routes.openapi("Test", "1.0.0");
+        assert_eq!(doc["openapi"], "3.1.0");
+        assert_eq!(doc["info"], json!({ "title": "Test", "version": "1.0.0" }));
+
+        let paths = &doc["paths"];
+        assert_eq!(paths["/"]["get"]["operationId"], "index");
+        assert!(paths["/"].get("head").is_none());
+
+        let user = &paths["/users/{id}"];
+        assert_eq!(user["get"]["operationId"], "users.show.get");
+        assert_eq!(user["delete"]["operationId"], "users.show.delete");
+        assert_eq!(user["get"]["summary"], "Show a user");
+        assert_eq!(user["get"]["tags"], json!(["users"]));
+        assert_eq!(user["get"]["parameters"][0]["name"], "id");
+
+        let files = &paths["/users/{id}/files/{path}"];
+        let methods: Vec<_> = files.as_object().unwrap().keys().collect();
+        assert_eq!(
+            methods,
+            ["delete", "get", "options", "patch", "post", "put", "trace"]
+        );
+        assert_eq!(files["post"]["operationId"], "users.files.post");
+        assert_eq!(files["get"]["parameters"].as_array().unwrap().len(), 2);
+
+        // the methods of axum's own method routers are not known
+        assert!(paths.get("/login").is_none());
+        assert!(paths.get("/logout").is_none());
+
+        let req = Request::builder()
+            .uri("/openapi.json")
+            .body(Body::empty())
+            .unwrap();
+        let body = app.oneshot(req).await.unwrap().into_body();
+        let served: Value =
+            serde_json::from_slice(&to_bytes(body, usize::MAX).await.unwrap()).unwrap();
+        assert_eq!(served, doc);
+    }
+}
@@ -0,0 +1,306 @@
+//! The URL path type used to store route paths
+//!
+//! Check out [`RoutePath`] for more information
+
+use std::{fmt, str::FromStr};
+
+/// A single `/` separated segment of a [`RoutePath`]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Segment {
+    /// A segment that is matched literally, like `users` in `/users/:id`
+    Static(String),
+    /// A named parameter that matches a single segment, like `:id` in `/users/:id`
+    Param(String),
+    /// A named wildcard that matches the rest of the path, like `*rest` in `/files/*rest`
+    Wildcard(String),
+}
+
+impl Segment {
+    fn parse(segment: &str) -> Result<Self, InvalidRoutePath> {
+        match segment.as_bytes().first() {
+            Some(b':') | Some(b'*') if segment.len() == 1 => Err(InvalidRoutePath::EmptyName),
+            Some(b':') => Ok(Self::Param(segment[1..].to_owned())),
+            Some(b'*') => Ok(Self::Wildcard(segment[1..].to_owned())),
+            _ => Ok(Self::Static(segment.to_owned())),
+        }
+    }
+
+    /// Returns the name of the parameter if this is a param or wildcard segment
+    pub fn param_name(&self) -> Option<&str> {
+        match self {
+            Self::Static(_) => None,
+            Self::Param(name) | Self::Wildcard(name) => Some(name),
+        }
+    }
+}
+
+impl fmt::Display for Segment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Static(s) => f.write_str(s),
+            Self::Param(name) => write!(f, ":{name}"),
+            Self::Wildcard(name) => write!(f, "*{name}"),
+        }
+    }
+}
+
+/// The error returned when a string is not a valid route path
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum InvalidRoutePath {
+    /// The path does not start with a `/`
+    MissingLeadingSlash,
+    /// A `:param` or `*wildcard` has no name
+    EmptyName,
+    /// A `*wildcard` is not the last segment of the path
+    WildcardNotLast,
+}
+
+impl fmt::Display for InvalidRoutePath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingLeadingSlash => f.write_str("paths must start with a `/`"),
+            Self::EmptyName => f.write_str("parameters and wildcards must have a name"),
+            Self::WildcardNotLast => f.write_str("wildcards are only allowed at the end of a path"),
+        }
+    }
+}
+
+impl std::error::Error for InvalidRoutePath {}
+
+/// A route path in the same syntax axum uses, like `/users/:id/files/*rest`
+///
+/// Unlike [`PathBuf`](std::path::PathBuf) this has URL semantics, the path is always
+/// `/` separated and joining works the same way axum nests routers.
+/// ```
+/// use axum_named_routes::{RoutePath, Segment};
+///
+/// let path: RoutePath = "/users/:id".parse().unwrap();
+/// assert_eq!(path, "/users/:id");
+/// assert_eq!(path.segments()[1], Segment::Param("id".into()));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RoutePath {
+    raw: String,
+    segments: Vec<Segment>,
+}
+
+impl RoutePath {
+    /// Parse a route path
+    pub fn parse(path: &str) -> Result<Self, InvalidRoutePath> {
+        let rest = path
+            .strip_prefix('/')
+            .ok_or(InvalidRoutePath::MissingLeadingSlash)?;
+        let segments = rest
+            .split('/')
+            .map(Segment::parse)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let last = segments.len() - 1;
+        if segments[..last]
+            .iter()
+            .any(|s| matches!(s, Segment::Wildcard(_)))
+        {
+            return Err(InvalidRoutePath::WildcardNotLast);
+        }
+
+        Ok(Self {
+            raw: path.to_owned(),
+            segments,
+        })
+    }
+
+    /// Parse a path that axum has already accepted as a route path
+    ///
+    /// # Panics
+    /// Panics if the path is not valid, axum panics on these paths first
+    pub(crate) fn from_router(path: &str) -> Self {
+        match Self::parse(path) {
+            Ok(path) => path,
+            Err(err) => panic!("invalid route path `{path}`: {err}"),
+        }
+    }
+
+    /// Parse a path given to `nest`, axum treats an empty prefix as `/`
+    pub(crate) fn from_nest(path: &str) -> Self {
+        Self::from_router(if path.is_empty() { "/" } else { path })
+    }
+
+    /// The path as a string, exactly as it was given to the router
+    pub fn as_str(&self) -> &str {
+        &self.raw
+    }
+
+    /// The `/` separated segments of the path
+    ///
+    /// The root path `/` has a single empty static segment
+    pub fn segments(&self) -> &[Segment] {
+        &self.segments
+    }
+
+    /// The names of all params and wildcards in the path in order
+    pub fn params(&self) -> impl Iterator<Item = &str> {
+        self.segments.iter().filter_map(Segment::param_name)
+    }
+
+    /// A route name derived from the path by joining its segments with `separator`
+    ///
+    /// Params and wildcards are named after their name, the root path is named `index`.
+    /// ```
+    /// use axum_named_routes::RoutePath;
+    ///
+    /// let path: RoutePath = "/users/:id/edit".parse().unwrap();
+    /// assert_eq!(path.to_name("."), "users.id.edit");
+    /// assert_eq!(RoutePath::parse("/").unwrap().to_name("."), "index");
+    /// ```
+    pub fn to_name(&self, separator: &str) -> String {
+        let parts: Vec<&str> = self
+            .segments
+            .iter()
+            .map(|segment| match segment {
+                Segment::Static(s) => s.as_str(),
+                Segment::Param(name) | Segment::Wildcard(name) => name.as_str(),
+            })
+            .filter(|part| !part.is_empty())
+            .collect();
+        if parts.is_empty() {
+            "index".to_owned()
+        } else {
+            parts.join(separator)
+        }
+    }
+
+    /// Join `other` onto the end of this path in the same way
+    /// [`Router::nest`](axum::Router::nest) joins a nested router's paths to its prefix.
+    /// ```
+    /// use axum_named_routes::RoutePath;
+    ///
+    /// let ui: RoutePath = "/ui/".parse().unwrap();
+    /// let api: RoutePath = "/api".parse().unwrap();
+    /// let other: RoutePath = "/other".parse().unwrap();
+    /// let root: RoutePath = "/".parse().unwrap();
+    ///
+    /// assert_eq!(ui.join(&other), "/ui/other");
+    /// assert_eq!(api.join(&other), "/api/other");
+    /// assert_eq!(api.join(&root), "/api");
+    /// ```
+    pub fn join(&self, other: &RoutePath) -> RoutePath {
+        let raw = if self.raw.ends_with('/') {
+            format!("{}{}", self.raw, other.raw.trim_start_matches('/'))
+        } else if other.raw == "/" {
+            self.raw.clone()
+        } else {
+            format!("{}{}", self.raw, other.raw)
+        };
+        let mut segments = self.segments.clone();
+        if self.raw.ends_with('/') {
+            // the trailing empty segment is replaced by the joined path
+            segments.pop();
+            segments.extend(other.segments.iter().cloned());
+        } else if other.raw != "/" {
+            segments.extend(other.segments.iter().cloned());
+        }
+        RoutePath { raw, segments }
+    }
+}
+
+impl FromStr for RoutePath {
+    type Err = InvalidRoutePath;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl AsRef<str> for RoutePath {
+    fn as_ref(&self) -> &str {
+        &self.raw
+    }
+}
+
+impl fmt::Display for RoutePath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.raw)
+    }
+}
+
+impl PartialEq<str> for RoutePath {
+    fn eq(&self, other: &str) -> bool {
+        self.raw == other
+    }
+}
+
+impl PartialEq<&str> for RoutePath {
+    fn eq(&self, other: &&str) -> bool {
+        self.raw == *other
+    }
+}
+
+impl PartialEq<RoutePath> for str {
+    fn eq(&self, other: &RoutePath) -> bool {
+        self == other.raw
+    }
+}
+
+impl PartialEq<RoutePath> for &str {
+    fn eq(&self, other: &RoutePath) -> bool {
+        *self == other.raw
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use crate::{path::InvalidRoutePath, RoutePath, Segment};
+
+    #[test]
+    fn parse() {
+        let path = RoutePath::parse("/users/:id/files/*rest").unwrap();
+        assert_eq!(
+            path.segments(),
+            &[
+                Segment::Static("users".into()),
+                Segment::Param("id".into()),
+                Segment::Static("files".into()),
+                Segment::Wildcard("rest".into()),
+            ]
+        );
+        assert_eq!(path.params().collect::<Vec<_>>(), ["id", "rest"]);
+        assert_eq!(
+            RoutePath::parse("/").unwrap().segments(),
+            &[Segment::Static("".into())]
+        );
+
+        assert_eq!(
+            RoutePath::parse("users"),
+            Err(InvalidRoutePath::MissingLeadingSlash)
+        );
+        assert_eq!(RoutePath::parse("/:"), Err(InvalidRoutePath::EmptyName));
+        assert_eq!(
+            RoutePath::parse("/*rest/a"),
+            Err(InvalidRoutePath::WildcardNotLast)
+        );
+    }
+
+    #[test]
+    fn join() {
+        let cases = [
+            ("/", "/a", "/a"),
+            ("/", "/", "/"),
+            ("/b", "/a", "/b/a"),
+            ("/b", "/", "/b"),
+            ("/b/", "/a", "/b/a"),
+            ("/b/", "/", "/b/"),
+            ("/users/:id", "/files/*rest", "/users/:id/files/*rest"),
+            ("", "/a", "/a"),
+            ("", "/", "/"),
+        ];
+        for (prefix, path, expected) in cases {
+            let prefix = RoutePath::from_nest(prefix);
+            let joined = prefix.join(&RoutePath::parse(path).unwrap());
+            assert_eq!(joined, expected);
+            assert_eq!(joined, RoutePath::parse(expected).unwrap());
+        }
+    }
+}
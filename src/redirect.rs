@@ -0,0 +1,224 @@
+//! Redirect responses to named routes
+//!
+//! Check out [`NamedRedirect`] for more information
+
+use axum::{
+    http::{header, HeaderValue, StatusCode},
+    response::{IntoResponse, Response},
+};
+use serde::Serialize;
+
+use crate::{url, Routes, UrlError};
+
+/// A response that redirects to a named route
+///
+/// The URL is generated when the response passes the router returned by
+/// [`into_router`](crate::NamedRouter::into_router), so handlers do not need the [`Routes`].
+/// Params that are not used in the route path are added as a query string like
+/// [`Routes::url_for_query`]. If the URL can not be generated the response is a
+/// `500 Internal Server Error` and the error is logged.
+///
+/// # Outside of the router
+///
+/// The redirect is only resolved by the router returned by
+/// [`into_router`](crate::NamedRouter::into_router). A `NamedRedirect` that never passes
+/// it, for example one returned from a handler of a plain [`axum::Router`] that was merged
+/// into the resulting router afterwards or from a test calling the handler directly, stays a
+/// `500 Internal Server Error` without a `Location` header. The redirect status is only set
+/// once the `Location` header is.
+/// ```
+/// use axum::routing::{get, post};
+/// use axum_named_routes::{NamedRedirect, NamedRouter};
+///
+/// async fn login() -> NamedRedirect {
+///     NamedRedirect::to("user", [("id", "4"), ("welcome", "1")])
+///     // Location: /users/4?welcome=1
+/// }
+///
+/// let app: NamedRouter = NamedRouter::new()
+///     .route("login", "/login", post(login))
+///     .route("user", "/users/:id", get(|| async {}));
+/// ```
+#[derive(Clone, Debug)]
+#[must_use = "needs to be returned from a handler or otherwise turned into a Response to be useful"]
+pub struct NamedRedirect {
+    target: Target,
+}
+
+/// The route a [`NamedRedirect`] response still has to be resolved to
+#[derive(Clone, Debug)]
+struct Target {
+    status: StatusCode,
+    name: String,
+    params: Result<Vec<(String, String)>, UrlError>,
+}
+
+impl NamedRedirect {
+    /// Redirect with `303 See Other`
+    ///
+    /// The client requests the route with `GET`, this is usually used after a form submission.
+    pub fn to<P: Serialize>(name: &str, params: P) -> Self {
+        Self::with_status(StatusCode::SEE_OTHER, name, params)
+    }
+
+    /// Redirect with `307 Temporary Redirect`, the client keeps the method and body
+    pub fn temporary<P: Serialize>(name: &str, params: P) -> Self {
+        Self::with_status(StatusCode::TEMPORARY_REDIRECT, name, params)
+    }
+
+    /// Redirect with `308 Permanent Redirect`, the client keeps the method and body
+    pub fn permanent<P: Serialize>(name: &str, params: P) -> Self {
+        Self::with_status(StatusCode::PERMANENT_REDIRECT, name, params)
+    }
+
+    fn with_status<P: Serialize>(status: StatusCode, name: &str, params: P) -> Self {
+        Self {
+            target: Target {
+                status,
+                name: name.to_owned(),
+                params: url::collect_params(params),
+            },
+        }
+    }
+
+    /// The status code of the redirect
+    pub fn status(&self) -> StatusCode {
+        self.target.status
+    }
+
+    /// The name of the route to redirect to
+    pub fn name(&self) -> &str {
+        &self.target.name
+    }
+}
+
+impl IntoResponse for NamedRedirect {
+    fn into_response(self) -> Response {
+        // Stays an error until `resolve` sets the `Location` header
+        let mut res = StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        res.extensions_mut().insert(self.target);
+        res
+    }
+}
+
+/// Set the `Location` header of a [`NamedRedirect`] response
+pub(crate) fn resolve(routes: &Routes, mut res: Response) -> Response {
+    let Some(target) = res.extensions_mut().remove::<Target>() else {
+        return res;
+    };
+    let location = target.params.and_then(|params| {
+        let path = routes
+            .try_get(&target.name)
+            .map_err(UrlError::UnknownRoute)?;
+        url::fill_path(path, params).with_query(&target.name)
+    });
+    match location.map(HeaderValue::try_from) {
+        Ok(Ok(location)) => {
+            *res.status_mut() = target.status;
+            res.headers_mut().insert(header::LOCATION, location);
+            res
+        }
+        Ok(Err(err)) => {
+            tracing::error!(route = %target.name, "invalid redirect location: {err}");
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+        Err(err) => {
+            tracing::error!(route = %target.name, "failed to redirect to named route: {err}");
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use axum::{
+        body::Body,
+        http::{header, Request, StatusCode},
+        routing::get,
+    };
+    use tower::ServiceExt;
+
+    use crate::{NamedRedirect, NamedRouter};
+
+    async fn call(app: &axum::Router, uri: &str) -> (StatusCode, Option<String>) {
+        let req = Request::builder().uri(uri).body(Body::empty()).unwrap();
+        let res = app.clone().oneshot(req).await.unwrap();
+        let location = res
+            .headers()
+            .get(header::LOCATION)
+            .map(|v| v.to_str().unwrap().to_owned());
+        (res.status(), location)
+    }
+
+    #[tokio::test]
+    async fn redirect() {
+        let users = NamedRouter::new().route("show", "/:id", get(|| async {}));
+        let app = NamedRouter::new()
+            .route(
+                "to",
+                "/to",
+                get(|| async { NamedRedirect::to("users.show", [("id", "4"), ("tab", "posts")]) }),
+            )
+            .route(
+                "temporary",
+                "/temporary",
+                get(|| async { NamedRedirect::temporary("users.show", [("id", "5")]) }),
+            )
+            .route(
+                "permanent",
+                "/permanent",
+                get(|| async { NamedRedirect::permanent("users.show", [("id", "6")]) }),
+            )
+            .route(
+                "unknown",
+                "/unknown",
+                get(|| async { NamedRedirect::to("missing", ()) }),
+            )
+            .route(
+                "missing_param",
+                "/missing_param",
+                get(|| async { NamedRedirect::to("users.show", ()) }),
+            )
+            .nest("users", "/users", users)
+            .into_router();
+
+        assert_eq!(
+            call(&app, "/to").await,
+            (StatusCode::SEE_OTHER, Some("/users/4?tab=posts".to_owned()))
+        );
+        assert_eq!(
+            call(&app, "/temporary").await,
+            (StatusCode::TEMPORARY_REDIRECT, Some("/users/5".to_owned()))
+        );
+        assert_eq!(
+            call(&app, "/permanent").await,
+            (StatusCode::PERMANENT_REDIRECT, Some("/users/6".to_owned()))
+        );
+        assert_eq!(
+            call(&app, "/unknown").await,
+            (StatusCode::INTERNAL_SERVER_ERROR, None)
+        );
+        assert_eq!(
+            call(&app, "/missing_param").await,
+            (StatusCode::INTERNAL_SERVER_ERROR, None)
+        );
+    }
+
+    #[tokio::test]
+    async fn unresolved() {
+        // merged after `into_router`, so the redirect never passes the layer
+        let plain =
+            axum::Router::new().route("/plain", get(|| async { NamedRedirect::to("index", ()) }));
+        let app = NamedRouter::new()
+            .route("index", "/", get(|| async {}))
+            .into_router()
+            .merge(plain);
+
+        assert_eq!(
+            call(&app, "/plain").await,
+            (StatusCode::INTERNAL_SERVER_ERROR, None)
+        );
+    }
+}
@@ -0,0 +1,266 @@
+//! Method routing that records the HTTP methods of each route
+//!
+//! axum's [`MethodRouter`] does not expose which methods it accepts, so the functions in
+//! this module build a [`MethodRoutes`] that keeps track of them. Routes added with
+//! axum's own [`get`](axum::routing::get), [`post`](axum::routing::post), ... work the
+//! same but their [`RouteInfo::methods`](crate::RouteInfo::methods) are
+//! [`Methods::Unknown`].
+//! ```
+//! use axum::http::Method;
+//! use axum_named_routes::{
+//!     routing::{get, Methods},
+//!     NamedRouter,
+//! };
+//!
+//! let app: NamedRouter = NamedRouter::new()
+//!     .route("users", "/users", get(|| async {}).post(|| async {}))
+//!     .route("legacy", "/legacy", axum::routing::get(|| async {}));
+//!
+//! assert_eq!(
+//!     app.routes()["users"].methods(),
+//!     &Methods::Only(vec![Method::GET, Method::HEAD, Method::POST])
+//! );
+//! assert_eq!(app.routes()["legacy"].methods(), &Methods::Unknown);
+//! ```
+
+use axum::{
+    handler::Handler,
+    http::Method,
+    routing::{MethodFilter, MethodRouter},
+};
+
+/// Every method a [`MethodFilter`] can match
+const FILTERS: [(MethodFilter, Method); 9] = [
+    (MethodFilter::CONNECT, Method::CONNECT),
+    (MethodFilter::DELETE, Method::DELETE),
+    (MethodFilter::GET, Method::GET),
+    (MethodFilter::HEAD, Method::HEAD),
+    (MethodFilter::OPTIONS, Method::OPTIONS),
+    (MethodFilter::PATCH, Method::PATCH),
+    (MethodFilter::POST, Method::POST),
+    (MethodFilter::PUT, Method::PUT),
+    (MethodFilter::TRACE, Method::TRACE),
+];
+
+/// The HTTP methods a route accepts
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Methods {
+    /// The route accepts any method, like routes added with [`any`] or services added
+    /// without a method list
+    Any,
+    /// The route only accepts these methods
+    Only(Vec<Method>),
+    /// The methods are not known because the route was not built with the functions in
+    /// [`routing`](crate::routing), like routes using axum's own [`MethodRouter`]
+    Unknown,
+}
+
+impl Methods {
+    /// Returns whether `method` is accepted, `None` if the methods are [`Unknown`](Methods::Unknown)
+    pub fn accepts(&self, method: &Method) -> Option<bool> {
+        match self {
+            Self::Any => Some(true),
+            Self::Only(methods) => Some(methods.contains(method)),
+            Self::Unknown => None,
+        }
+    }
+
+    /// The methods if the route only accepts some methods
+    pub fn only(&self) -> Option<&[Method]> {
+        match self {
+            Self::Only(methods) => Some(methods),
+            Self::Any | Self::Unknown => None,
+        }
+    }
+}
+
+/// A [`MethodRouter`] together with the HTTP methods it accepts
+///
+/// This is built with the functions in [`routing`](crate::routing) and is accepted
+/// everywhere a [`MethodRouter`] is, like [`NamedRouter::route`](crate::NamedRouter::route).
+#[derive(Debug)]
+#[must_use]
+pub struct MethodRoutes<S = ()> {
+    router: MethodRouter<S>,
+    methods: Methods,
+}
+
+impl<S> MethodRoutes<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    /// Wrap a [`MethodRouter`] and declare the methods it accepts
+    ///
+    /// The methods are only recorded, they are not checked against `router`.
+    pub fn new<M>(router: MethodRouter<S>, methods: M) -> Self
+    where
+        M: IntoIterator<Item = Method>,
+    {
+        Self {
+            router,
+            methods: Methods::Only(methods.into_iter().collect()),
+        }
+    }
+
+    /// The methods the routes accept
+    pub fn methods(&self) -> &Methods {
+        &self.methods
+    }
+
+    /// Route requests with a method matched by `filter` to `handler`
+    pub fn on<H, T>(mut self, filter: MethodFilter, handler: H) -> Self
+    where
+        H: Handler<T, S>,
+        T: 'static,
+    {
+        self.router = self.router.on(filter, handler);
+        if let Methods::Only(methods) = &mut self.methods {
+            for method in filter_methods(filter) {
+                if !methods.contains(&method) {
+                    methods.push(method);
+                }
+            }
+        }
+        self
+    }
+
+    /// Change the inner [`MethodRouter`], like to add a layer
+    ///
+    /// The recorded methods are kept so `f` should not change which methods are accepted.
+    pub fn map_router<F>(self, f: F) -> Self
+    where
+        F: FnOnce(MethodRouter<S>) -> MethodRouter<S>,
+    {
+        Self {
+            router: f(self.router),
+            methods: self.methods,
+        }
+    }
+
+    /// Turn into the inner [`MethodRouter`] and the methods it accepts
+    pub fn into_parts(self) -> (MethodRouter<S>, Methods) {
+        (self.router, self.methods)
+    }
+}
+
+impl<S> From<MethodRouter<S>> for MethodRoutes<S> {
+    /// Wrap a [`MethodRouter`] whose methods are unknown
+    fn from(router: MethodRouter<S>) -> Self {
+        Self {
+            router,
+            methods: Methods::Unknown,
+        }
+    }
+}
+
+/// The methods matched by `filter`, a `GET` handler also handles `HEAD` in axum
+fn filter_methods(filter: MethodFilter) -> Vec<Method> {
+    let mut methods: Vec<Method> = FILTERS
+        .iter()
+        .filter(|(single, _)| filter.or(*single) == filter)
+        .map(|(_, method)| method.clone())
+        .collect();
+    if methods.contains(&Method::GET) && !methods.contains(&Method::HEAD) {
+        let get = methods.iter().position(|method| method == Method::GET);
+        methods.insert(get.map_or(0, |i| i + 1), Method::HEAD);
+    }
+    methods
+}
+
+/// Route requests with a method matched by `filter` to `handler`
+pub fn on<H, T, S>(filter: MethodFilter, handler: H) -> MethodRoutes<S>
+where
+    H: Handler<T, S>,
+    T: 'static,
+    S: Clone + Send + Sync + 'static,
+{
+    MethodRoutes::new(MethodRouter::new(), []).on(filter, handler)
+}
+
+/// Route requests of any method to `handler`
+pub fn any<H, T, S>(handler: H) -> MethodRoutes<S>
+where
+    H: Handler<T, S>,
+    T: 'static,
+    S: Clone + Send + Sync + 'static,
+{
+    MethodRoutes {
+        router: axum::routing::any(handler),
+        methods: Methods::Any,
+    }
+}
+
+macro_rules! method_fns {
+    ($($name:ident, $filter:ident;)*) => {
+        $(
+            #[doc = concat!("Route `", stringify!($filter), "` requests to `handler`")]
+            pub fn $name<H, T, S>(handler: H) -> MethodRoutes<S>
+            where
+                H: Handler<T, S>,
+                T: 'static,
+                S: Clone + Send + Sync + 'static,
+            {
+                on(MethodFilter::$filter, handler)
+            }
+        )*
+
+        impl<S> MethodRoutes<S>
+        where
+            S: Clone + Send + Sync + 'static,
+        {
+            $(
+                #[doc = concat!("Chain a handler for `", stringify!($filter), "` requests")]
+                pub fn $name<H, T>(self, handler: H) -> Self
+                where
+                    H: Handler<T, S>,
+                    T: 'static,
+                {
+                    self.on(MethodFilter::$filter, handler)
+                }
+            )*
+        }
+    };
+}
+
+method_fns! {
+    connect, CONNECT;
+    delete, DELETE;
+    get, GET;
+    head, HEAD;
+    options, OPTIONS;
+    patch, PATCH;
+    post, POST;
+    put, PUT;
+    trace, TRACE;
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{http::Method, routing::MethodFilter};
+
+    use super::{any, get, on, MethodRoutes, Methods};
+
+    async fn dummy() {}
+
+    #[test]
+    fn methods() {
+        let get_post: MethodRoutes = get(dummy).post(dummy);
+        assert_eq!(
+            get_post.methods(),
+            &Methods::Only(vec![Method::GET, Method::HEAD, Method::POST])
+        );
+        assert_eq!(get_post.methods().accepts(&Method::DELETE), Some(false));
+        let put_delete: MethodRoutes = on(MethodFilter::PUT.or(MethodFilter::DELETE), dummy);
+        assert_eq!(
+            put_delete.methods(),
+            &Methods::Only(vec![Method::DELETE, Method::PUT])
+        );
+        let any: MethodRoutes = any(dummy).post(dummy);
+        assert_eq!(any.methods(), &Methods::Any);
+        assert_eq!(any.methods().accepts(&Method::DELETE), Some(true));
+        let unknown: MethodRoutes = axum::routing::get(dummy).into();
+        assert_eq!(unknown.methods(), &Methods::Unknown);
+        assert_eq!(unknown.methods().accepts(&Method::GET), None);
+    }
+}
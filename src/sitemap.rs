@@ -0,0 +1,471 @@
+//! `sitemap.xml` generation from the named routes
+//!
+//! Check out [`Sitemap`] for more information
+
+use std::{collections::HashMap, fmt, fmt::Write, sync::Arc};
+
+use axum::{
+    extract::MatchedPath,
+    http::{header, Extensions, Method, StatusCode, Uri},
+    response::{IntoResponse, Response},
+};
+use serde::Serialize;
+
+use crate::{routing::get, url, NamedRouter, Params, Routes, UrlError};
+
+/// The most URLs a single sitemap file may contain
+pub const MAX_URLS: usize = 50_000;
+
+type Provider = Arc<dyn Fn() -> Result<Vec<Params>, UrlError> + Send + Sync>;
+
+/// How often the page of a route is likely to change
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(missing_docs)]
+pub enum ChangeFreq {
+    Always,
+    Hourly,
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+    Never,
+}
+
+impl fmt::Display for ChangeFreq {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Always => "always",
+            Self::Hourly => "hourly",
+            Self::Daily => "daily",
+            Self::Weekly => "weekly",
+            Self::Monthly => "monthly",
+            Self::Yearly => "yearly",
+            Self::Never => "never",
+        })
+    }
+}
+
+/// Sitemap details of a named route, attached as metadata with
+/// [`route_with_meta`](NamedRouter::route_with_meta) or set with [`Sitemap::meta`]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SitemapMeta {
+    lastmod: Option<String>,
+    changefreq: Option<ChangeFreq>,
+    priority: Option<f32>,
+    include: bool,
+    exclude: bool,
+}
+
+impl SitemapMeta {
+    /// Create sitemap details without any values set
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set when the page was last modified, in W3C datetime format like `2024-01-31`
+    pub fn lastmod<T: Into<String>>(mut self, lastmod: T) -> Self {
+        self.lastmod = Some(lastmod.into());
+        self
+    }
+
+    /// Set how often the page is likely to change
+    pub fn changefreq(mut self, changefreq: ChangeFreq) -> Self {
+        self.changefreq = Some(changefreq);
+        self
+    }
+
+    /// Set the priority of the page relative to the other pages, between `0.0` and `1.0`
+    pub fn priority(mut self, priority: f32) -> Self {
+        self.priority = Some(priority.clamp(0.0, 1.0));
+        self
+    }
+
+    /// Include the route in the sitemap even if it is not known to accept `GET`
+    ///
+    /// This is needed for routes with [`Unknown`](crate::routing::Methods::Unknown) methods,
+    /// like routes using axum's own method routers.
+    pub fn include(mut self) -> Self {
+        self.include = true;
+        self
+    }
+
+    /// Leave the route out of the sitemap
+    pub fn exclude(mut self) -> Self {
+        self.exclude = true;
+        self
+    }
+}
+
+/// Metadata leaving a route out of the sitemap, for routes that serve generated documents
+pub(crate) fn excluded() -> Extensions {
+    let mut meta = Extensions::new();
+    meta.insert(SitemapMeta::new().exclude());
+    meta
+}
+
+/// A generator for `sitemap.xml` files from the named routes
+///
+/// Every named route without path parameters that is known to accept `GET` is included
+/// by default, routes with parameters are included once for each set of parameters
+/// returned by their [`provider`](Sitemap::provider). Routes with
+/// [`Unknown`](crate::routing::Methods::Unknown) methods have to be included with
+/// [`SitemapMeta::include`].
+/// ```
+/// use axum_named_routes::{routing::get, sitemap::Sitemap, NamedRouter};
+///
+/// let app: NamedRouter = NamedRouter::new()
+///     .base_url("https://example.com")
+///     .route("index", "/", get(|| async {}))
+///     .route("post", "/posts/:slug", get(|| async {}));
+/// let (_, routes) = app.into_parts();
+///
+/// let sitemap = Sitemap::new().provider("post", || [[("slug", "hello")]]);
+/// let files = sitemap.render(&routes).unwrap();
+/// assert!(files.sitemaps()[0].contains("<loc>https://example.com/posts/hello</loc>"));
+/// ```
+#[derive(Clone, Default)]
+pub struct Sitemap {
+    base_url: Option<String>,
+    path: Option<String>,
+    max_urls: Option<usize>,
+    providers: HashMap<String, Provider>,
+    meta: HashMap<String, SitemapMeta>,
+}
+
+impl Sitemap {
+    /// Create a sitemap for the routes using their [`base_url`](Routes::base_url)
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the base URL the paths of the routes are prefixed with
+    pub fn base_url<T: Into<String>>(mut self, url: T) -> Self {
+        self.base_url = Some(url.into().trim_end_matches('/').to_owned());
+        self
+    }
+
+    /// Set the path the sitemap is served at, `/sitemap.xml` by default
+    ///
+    /// When the sitemap is split the index links to the parts with a `page` query
+    /// parameter on this path.
+    pub fn path<T: Into<String>>(mut self, path: T) -> Self {
+        self.path = Some(path.into());
+        self
+    }
+
+    /// Set the most URLs in each sitemap file before it is split, at most [`MAX_URLS`]
+    pub fn max_urls(mut self, max_urls: usize) -> Self {
+        self.max_urls = Some(max_urls.clamp(1, MAX_URLS));
+        self
+    }
+
+    /// Set the function that returns the path parameters for every page of a route
+    ///
+    /// The parameters can be anything accepted by [`Routes::url_for`]. The function is
+    /// called each time the sitemap is rendered.
+    pub fn provider<N, F, I, P>(mut self, name: N, provider: F) -> Self
+    where
+        N: Into<String>,
+        F: Fn() -> I + Send + Sync + 'static,
+        I: IntoIterator<Item = P>,
+        P: Serialize,
+    {
+        let provider = move || {
+            provider()
+                .into_iter()
+                .map(|params| url::collect_params(params).map(Params::from_iter))
+                .collect()
+        };
+        self.providers.insert(name.into(), Arc::new(provider));
+        self
+    }
+
+    /// Set the sitemap details of a route, this takes precedence over the route metadata
+    pub fn meta<N: Into<String>>(mut self, name: N, meta: SitemapMeta) -> Self {
+        self.meta.insert(name.into(), meta);
+        self
+    }
+
+    /// Render the sitemap files for `routes`
+    ///
+    /// Returns an error if there is no base URL or a provider returns invalid parameters.
+    pub fn render(&self, routes: &Routes) -> Result<SitemapFiles, UrlError> {
+        let base_url = self
+            .base_url
+            .as_deref()
+            .or_else(|| routes.base_url())
+            .ok_or(UrlError::NoBaseUrl)?;
+
+        let mut named: Vec<_> = routes.iter().collect();
+        named.sort_unstable_by_key(|(name, _)| *name);
+
+        let mut urls = Vec::new();
+        for (name, info) in named {
+            let meta = self.meta.get(name).or_else(|| info.meta());
+            let included = match meta {
+                Some(meta) if meta.exclude => false,
+                Some(meta) if meta.include => true,
+                _ => info.accepts(&Method::GET) == Some(true),
+            };
+            if !included {
+                continue;
+            }
+            let entry = |path: String| Entry {
+                loc: format!("{base_url}{path}"),
+                meta,
+            };
+            match self.providers.get(name) {
+                Some(provider) => {
+                    for params in provider()? {
+                        urls.push(entry(routes.url_for(name, params)?));
+                    }
+                }
+                None if info.path().params().next().is_none() => {
+                    urls.push(entry(info.path().to_string()));
+                }
+                None => {}
+            }
+        }
+
+        let max_urls = self.max_urls.unwrap_or(MAX_URLS);
+        if urls.len() <= max_urls {
+            return Ok(SitemapFiles {
+                index: None,
+                sitemaps: vec![urlset(&urls)],
+            });
+        }
+        let sitemaps: Vec<_> = urls.chunks(max_urls).map(urlset).collect();
+        let path = self.path.as_deref().unwrap_or("/sitemap.xml");
+        let index = sitemap_index(base_url, path, sitemaps.len());
+        Ok(SitemapFiles {
+            index: Some(index),
+            sitemaps,
+        })
+    }
+}
+
+impl fmt::Debug for Sitemap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Sitemap")
+            .field("base_url", &self.base_url)
+            .field("path", &self.path)
+            .field("max_urls", &self.max_urls)
+            .field("providers", &self.providers.keys().collect::<Vec<_>>())
+            .field("meta", &self.meta)
+            .finish()
+    }
+}
+
+/// The rendered files of a [`Sitemap`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SitemapFiles {
+    index: Option<String>,
+    sitemaps: Vec<String>,
+}
+
+impl SitemapFiles {
+    /// The sitemap index linking to every sitemap, only when there are multiple sitemaps
+    pub fn index(&self) -> Option<&str> {
+        self.index.as_deref()
+    }
+
+    /// The sitemaps, each containing at most [`max_urls`](Sitemap::max_urls) URLs
+    pub fn sitemaps(&self) -> &[String] {
+        &self.sitemaps
+    }
+
+    /// The file served at the sitemap path when no page is requested
+    fn root(&self) -> &str {
+        self.index.as_deref().unwrap_or(&self.sitemaps[0])
+    }
+}
+
+impl<S> NamedRouter<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    /// Add a named `GET` route that serves the rendered `sitemap`
+    ///
+    /// When the sitemap is split the index is served at `path` and the sitemaps at
+    /// `path?page=1` and onwards. The route itself is left out of the sitemap.
+    ///
+    /// # Panics
+    /// Panics if `name` is already used by another route
+    pub fn sitemap_route<N, P>(self, name: N, path: P, sitemap: Sitemap) -> Self
+    where
+        N: Into<crate::String>,
+        P: AsRef<str>,
+    {
+        let handler = move |routes: Routes, matched: MatchedPath, uri: Uri| async move {
+            let sitemap = match sitemap.path {
+                Some(_) => sitemap,
+                None => sitemap.path(matched.as_str()),
+            };
+            serve(&sitemap, &routes, &uri)
+        };
+        self.route_with_meta(name, path, get(handler), excluded())
+    }
+}
+
+fn serve(sitemap: &Sitemap, routes: &Routes, uri: &Uri) -> Response {
+    let files = match sitemap.render(routes) {
+        Ok(files) => files,
+        Err(err) => return (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()).into_response(),
+    };
+    let page = form_urlencoded::parse(uri.query().unwrap_or_default().as_bytes())
+        .find(|(key, _)| key == "page")
+        .map(|(_, page)| page.parse::<usize>().ok());
+    let body = match page {
+        None => files.root(),
+        Some(page) => match page.and_then(|page| files.sitemaps.get(page.checked_sub(1)?)) {
+            Some(sitemap) => sitemap.as_str(),
+            None => return StatusCode::NOT_FOUND.into_response(),
+        },
+    };
+    ([(header::CONTENT_TYPE, "application/xml")], body.to_owned()).into_response()
+}
+
+struct Entry<'a> {
+    loc: String,
+    meta: Option<&'a SitemapMeta>,
+}
+
+fn urlset(urls: &[Entry<'_>]) -> String {
+    let mut xml = String::from(concat!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>"#,
+        "\n",
+        r#"<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">"#,
+        "\n",
+    ));
+    for url in urls {
+        let _ = write!(xml, "  <url><loc>{}</loc>", escape(&url.loc));
+        if let Some(meta) = url.meta {
+            if let Some(lastmod) = &meta.lastmod {
+                let _ = write!(xml, "<lastmod>{}</lastmod>", escape(lastmod));
+            }
+            if let Some(changefreq) = meta.changefreq {
+                let _ = write!(xml, "<changefreq>{changefreq}</changefreq>");
+            }
+            if let Some(priority) = meta.priority {
+                let _ = write!(xml, "<priority>{priority:.1}</priority>");
+            }
+        }
+        xml.push_str("</url>\n");
+    }
+    xml.push_str("</urlset>\n");
+    xml
+}
+
+fn sitemap_index(base_url: &str, path: &str, count: usize) -> String {
+    let mut xml = String::from(concat!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>"#,
+        "\n",
+        r#"<sitemapindex xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">"#,
+        "\n",
+    ));
+    for page in 1..=count {
+        let loc = format!("{base_url}{path}?page={page}");
+        let _ = writeln!(xml, "  <sitemap><loc>{}</loc></sitemap>", escape(&loc));
+    }
+    xml.push_str("</sitemapindex>\n");
+    xml
+}
+
+fn escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use axum::{
+        body::{to_bytes, Body},
+        http::{Extensions, Request, StatusCode},
+    };
+    use tower::ServiceExt;
+
+    use crate::{
+        routing::{get, post},
+        sitemap::{ChangeFreq, Sitemap, SitemapMeta},
+        NamedRouter, UrlError,
+    };
+
+    async fn dummy() {}
+
+    #[tokio::test]
+    async fn sitemap() {
+        let mut meta = Extensions::new();
+        meta.insert(
+            SitemapMeta::new()
+                .changefreq(ChangeFreq::Daily)
+                .priority(2.0),
+        );
+        let sitemap = || {
+            Sitemap::new()
+                .provider("post", || (1..=3).map(|id| [("id", id)]))
+                .meta("admin", SitemapMeta::new().exclude())
+                .meta("about", SitemapMeta::new().include())
+        };
+        let (app, routes) = NamedRouter::new()
+            .base_url("https://example.com")
+            .route_with_meta("index", "/", get(dummy), meta)
+            .route("admin", "/admin", get(dummy))
+            .route("login", "/login", post(dummy))
+            .route("post", "/posts/:id", get(dummy))
+            .route("user", "/users/:id", get(dummy))
+            .route("legacy", "/legacy", axum::routing::post(dummy))
+            .route("about", "/about", axum::routing::get(dummy))
+            .listing_route("routes", "/_routes")
+            .sitemap_route("sitemap", "/sitemap.xml", sitemap().max_urls(2))
+            .into_parts();
+
+        let files = sitemap().render(&routes).unwrap();
+        assert_eq!(files.index(), None);
+        let xml = &files.sitemaps()[0];
+        assert!(xml.contains(
+            "<url><loc>https://example.com/</loc><changefreq>daily</changefreq><priority>1.0</priority></url>"
+        ));
+        assert!(xml.contains("<loc>https://example.com/posts/3</loc>"));
+        assert!(xml.contains("<loc>https://example.com/about</loc>"));
+        assert!(!xml.contains("admin") && !xml.contains("login") && !xml.contains("users"));
+        // unknown methods are only included explicitly
+        assert!(!xml.contains("legacy"));
+        assert!(!xml.contains("sitemap.xml") && !xml.contains("_routes"));
+        assert_eq!(xml.matches("<url>").count(), 5);
+
+        let call = |uri: &'static str| {
+            let app = app.clone();
+            async move {
+                let req = Request::builder().uri(uri).body(Body::empty()).unwrap();
+                let res = app.oneshot(req).await.unwrap();
+                let status = res.status();
+                let body = to_bytes(res.into_body(), usize::MAX).await.unwrap();
+                (status, String::from_utf8(body.to_vec()).unwrap())
+            }
+        };
+        let (_, index) = call("/sitemap.xml").await;
+        assert!(index.contains("<sitemapindex"));
+        assert!(index.contains("<loc>https://example.com/sitemap.xml?page=2</loc>"));
+        let (_, page) = call("/sitemap.xml?page=2").await;
+        assert_eq!(page.matches("<url>").count(), 2);
+        assert_eq!(call("/sitemap.xml?page=4").await.0, StatusCode::NOT_FOUND);
+
+        let no_base = NamedRouter::<()>::new().into_parts().1;
+        assert_eq!(
+            Sitemap::new().render(&no_base).unwrap_err(),
+            UrlError::NoBaseUrl
+        );
+    }
+}
@@ -0,0 +1,198 @@
+//! Generating URLs from named routes in templates
+//!
+//! Check out [`UrlFor`] for more information
+
+use serde::Serialize;
+
+use crate::{Routes, UrlError};
+
+/// URL generation for template types that have access to the [`Routes`]
+///
+/// This is meant for templates that are compiled into Rust types like askama, which can
+/// call methods on the template. Parameters that are not used in the route path are added
+/// as a query string like [`Routes::url_for_query`].
+/// ```
+/// use axum::routing::get;
+/// use axum_named_routes::{NamedRouter, Routes, UrlFor};
+///
+/// // #[derive(askama::Template)]
+/// // #[template(source = r#"<a href="{{ self.url_for("user", [("id", id)])? }}">"#, ext = "html")]
+/// struct UserLink {
+///     routes: Routes,
+///     id: u64,
+/// }
+///
+/// impl UrlFor for UserLink {
+///     fn routes(&self) -> &Routes {
+///         &self.routes
+///     }
+/// }
+///
+/// let app: NamedRouter = NamedRouter::new()
+///     .route("user", "/users/:id", get(|| async {}));
+/// let (_, routes) = app.into_parts();
+///
+/// let link = UserLink { routes, id: 4 };
+/// assert_eq!(link.url_for("user", [("id", link.id)]).unwrap(), "/users/4");
+/// ```
+pub trait UrlFor {
+    /// The routes to generate URLs from
+    fn routes(&self) -> &Routes;
+
+    /// Generate a URL for the route with the given name
+    fn url_for<P: Serialize>(&self, name: &str, params: P) -> Result<String, UrlError> {
+        self.routes().url_for_query(name, params)
+    }
+}
+
+impl UrlFor for Routes {
+    fn routes(&self) -> &Routes {
+        self
+    }
+}
+
+#[cfg(feature = "minijinja")]
+impl Routes {
+    /// Add a `url_for` function to a minijinja environment
+    ///
+    /// The route name is the first argument and the parameters are keyword arguments,
+    /// parameters that are not used in the route path are added as a query string.
+    /// ```
+    /// use axum::routing::get;
+    /// use axum_named_routes::NamedRouter;
+    ///
+    /// let app: NamedRouter = NamedRouter::new()
+    ///     .route("user", "/users/:id", get(|| async {}));
+    /// let (_, routes) = app.into_parts();
+    ///
+    /// let mut env = minijinja::Environment::new();
+    /// routes.register_minijinja(&mut env);
+    ///
+    /// let url = env.render_str(r#"{{ url_for("user", id=4, tab="posts") }}"#, ()).unwrap();
+    /// assert_eq!(url, "/users/4?tab=posts");
+    /// ```
+    pub fn register_minijinja(&self, env: &mut minijinja::Environment<'_>) {
+        use minijinja::{value::Kwargs, Error, ErrorKind, Value};
+
+        let routes = self.clone();
+        env.add_function("url_for", move |name: &str, kwargs: Kwargs| {
+            let params = kwargs
+                .args()
+                .map(|key| Ok((key.to_owned(), kwargs.get::<Value>(key)?.to_string())))
+                .collect::<Result<Vec<_>, Error>>()?;
+            routes
+                .url_for_query(name, params)
+                .map_err(|err| Error::new(ErrorKind::InvalidOperation, err.to_string()))
+        });
+    }
+}
+
+#[cfg(feature = "tera")]
+impl Routes {
+    /// Add a `url_for` function to a tera instance
+    ///
+    /// Tera functions only take keyword arguments so the route name is the `name` argument
+    /// and all other arguments are the parameters, parameters that are not used in the
+    /// route path are added as a query string sorted by name, as tera does not keep the
+    /// order of arguments. Tera escapes `/` when autoescaping so the result usually needs
+    /// the `safe` filter.
+    /// ```
+    /// use axum::routing::get;
+    /// use axum_named_routes::NamedRouter;
+    ///
+    /// let app: NamedRouter = NamedRouter::new()
+    ///     .route("user", "/users/:id", get(|| async {}));
+    /// let (_, routes) = app.into_parts();
+    ///
+    /// let mut tera = tera::Tera::default();
+    /// routes.register_tera(&mut tera);
+    ///
+    /// let template = r#"{{ url_for(name="user", id=4, tab="posts") }}"#;
+    /// let url = tera.render_str(template, &tera::Context::new()).unwrap();
+    /// assert_eq!(url, "/users/4?tab=posts");
+    /// ```
+    pub fn register_tera(&self, tera: &mut tera::Tera) {
+        use std::collections::HashMap;
+
+        use tera::{Error, Value};
+
+        let routes = self.clone();
+        tera.register_function("url_for", move |args: &HashMap<String, Value>| {
+            let name = match args.get("name") {
+                Some(Value::String(name)) => name,
+                _ => return Err(Error::msg("`url_for` requires a string `name` argument")),
+            };
+            let mut params: Vec<(&str, String)> = args
+                .iter()
+                .filter(|(key, _)| *key != "name")
+                .map(|(key, value)| match value {
+                    Value::String(value) => (key.as_str(), value.clone()),
+                    value => (key.as_str(), value.to_string()),
+                })
+                .collect();
+            // the arguments are a `HashMap`, sort them so the query string is stable
+            params.sort_unstable_by_key(|(key, _)| *key);
+            routes
+                .url_for_query(name, params)
+                .map(Value::String)
+                .map_err(|err| Error::msg(err.to_string()))
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use askama::Template;
+    use axum::routing::get;
+
+    use crate::{NamedRouter, Routes, UrlFor};
+
+    #[derive(Template)]
+    #[template(
+        source = r#"<a href="{{ self.url_for("user", [("id", id)])? }}">{{ id }}</a>"#,
+        ext = "html"
+    )]
+    struct UserLink {
+        routes: Routes,
+        id: u64,
+    }
+
+    impl UrlFor for UserLink {
+        fn routes(&self) -> &Routes {
+            &self.routes
+        }
+    }
+
+    #[test]
+    fn askama() {
+        let app: NamedRouter = NamedRouter::new().route("user", "/users/:id", get(|| async {}));
+        let (_, routes) = app.into_parts();
+
+        let link = UserLink {
+            routes: routes.clone(),
+            id: 4,
+        };
+        assert_eq!(link.render().unwrap(), r#"<a href="/users/4">4</a>"#);
+
+        let missing = UserLink { routes, id: 4 }.url_for("missing", ());
+        assert!(missing.is_err());
+    }
+
+    #[cfg(feature = "tera")]
+    #[test]
+    fn tera() {
+        let app: NamedRouter = NamedRouter::new().route("user", "/users/:id", get(|| async {}));
+        let (_, routes) = app.into_parts();
+        let mut tera = tera::Tera::default();
+        routes.register_tera(&mut tera);
+
+        let template = r#"{{ url_for(name="user", id=4, tab="posts", page=2, b="x", a="y") }}"#;
+        let url = tera.render_str(template, &tera::Context::new()).unwrap();
+        assert_eq!(url, "/users/4?a=y&b=x&page=2&tab=posts");
+
+        let missing = tera.render_str(r#"{{ url_for(name="missing") }}"#, &tera::Context::new());
+        assert!(missing.is_err());
+    }
+}
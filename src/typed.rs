@@ -0,0 +1,101 @@
+//! Typed route names
+//!
+//! Check out [`NamedRoutes`] for more information
+
+use crate::{Params, RoutePath, UrlError};
+
+/// A route name and path known at compile time
+///
+/// These are usually generated by `#[derive(NamedRoutes)]` and registered with
+/// [`NamedRouter::typed_route`](crate::NamedRouter::typed_route).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RouteDef {
+    name: &'static str,
+    path: &'static str,
+}
+
+impl RouteDef {
+    /// Create a new route definition
+    pub const fn new(name: &'static str, path: &'static str) -> Self {
+        Self { name, path }
+    }
+
+    /// The name of the route
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// The path of the route in axum syntax
+    pub const fn path(&self) -> &'static str {
+        self.path
+    }
+}
+
+/// A type where each value is a route with all of its path parameters
+///
+/// This is meant to be derived on an enum with the `macros` feature. Every variant
+/// needs a `#[route("/path")]` attribute, the fields of the variant must exactly match the
+/// parameters in the path and are converted using their [`Display`](std::fmt::Display)
+/// implementation. The route name is the variant name in `snake_case` unless it is
+/// set with `#[route("/path", name = "name")]`. The derive also adds a [`RouteDef`]
+/// constant for each variant named after the variant in `SCREAMING_SNAKE_CASE`.
+///
+/// Typed routes are looked up by their name so they should be registered on the root
+/// router, nesting them would add a prefix to their names.
+#[cfg_attr(feature = "macros", doc = "```")]
+#[cfg_attr(not(feature = "macros"), doc = "```ignore")]
+/// use axum::routing::get;
+/// use axum_named_routes::{NamedRouter, NamedRoutes, Routes};
+///
+/// #[derive(NamedRoutes)]
+/// enum AppRoute {
+///     #[route("/")]
+///     Index,
+///     #[route("/users/:id", name = "users.show")]
+///     User { id: u64 },
+/// }
+///
+/// async fn index(routes: Routes) -> String {
+///     routes.typed_url(&AppRoute::User { id: 4 }).unwrap()
+/// }
+///
+/// let app: NamedRouter = NamedRouter::new()
+///     .typed_route(AppRoute::INDEX, get(index))
+///     .typed_route(AppRoute::USER, get(|| async {}));
+///
+/// assert_eq!(app.routes()["users.show"].path(), "/users/:id");
+/// assert_eq!(AppRoute::User { id: 4 }.to_path().unwrap(), "/users/4");
+/// ```
+pub trait NamedRoutes {
+    /// The definitions of every route
+    const ROUTES: &'static [RouteDef];
+
+    /// The definition of this route
+    fn route_def(&self) -> RouteDef;
+
+    /// The path parameters of this route
+    fn params(&self) -> Params;
+
+    /// The path of this route with all parameters filled in
+    ///
+    /// This uses the path the route was declared with, use
+    /// [`Routes::typed_url`](crate::Routes::typed_url) to get the path the route was
+    /// registered with. Like [`typed_url`](crate::Routes::typed_url) this returns an error
+    /// if a parameter is a `.` or `..` path segment.
+    fn to_path(&self) -> Result<String, UrlError> {
+        let def = self.route_def();
+        let path = RoutePath::from_router(def.path());
+        crate::url::fill_path(&path, self.params().into_pairs()).strict(def.name())
+    }
+}
+
+#[doc(hidden)]
+pub mod __private {
+    use crate::{Params, RoutePath, UrlError};
+
+    /// Used by `url!` to fill a path that was already checked at compile time
+    pub fn fill(name: &str, path: &'static str, params: Params) -> Result<String, UrlError> {
+        let path = RoutePath::from_router(path);
+        crate::url::fill_path(&path, params.into_pairs()).strict(name)
+    }
+}
@@ -0,0 +1,195 @@
+//! TypeScript route helpers generated from a [`Manifest`]
+//!
+//! Check out [`Manifest::to_typescript`] for more information
+
+use std::{collections::BTreeMap, fmt::Write};
+
+use crate::manifest::{Manifest, ManifestRoute};
+
+const HEADER: &str = r#"// Generated by axum-named-routes, do not edit
+
+export type Param = string | number;
+
+function fill(path: string, params: Record<string, Param>): string {
+  return path.replace(/\/([:*])([^/]+)/g, (_, kind: string, name: string) => {
+    const value = String(params[name]);
+    const encoded =
+      kind === "*"
+        ? value.split("/").map(encodeURIComponent).join("/")
+        : encodeURIComponent(value);
+    return "/" + encoded;
+  });
+}
+
+function nest<F extends Function, C extends object>(route: F, children: C): F & C {
+  // `Object.assign` fails for children named like the read only `name` and `length`
+  for (const [key, value] of Object.entries(children)) {
+    Object.defineProperty(route, key, { value, enumerable: true });
+  }
+  return route as F & C;
+}
+"#;
+
+/// Route names split on the separator into a tree so nested routes become nested objects
+#[derive(Default)]
+struct Node<'a> {
+    route: Option<&'a ManifestRoute>,
+    children: BTreeMap<&'a str, Node<'a>>,
+}
+
+impl Manifest {
+    /// Generate a TypeScript module with a typed function for every route
+    ///
+    /// Route names are split on the [`separator`](Manifest::separator) into nested objects,
+    /// so the route `users.show` with the path `/users/:id` becomes
+    /// `routes.users.show({ id })`. Calling a function with missing params is a type error.
+    /// ```
+    /// use axum::routing::get;
+    /// use axum_named_routes::NamedRouter;
+    ///
+    /// let app: NamedRouter = NamedRouter::new()
+    ///     .route("users.show", "/users/:id", get(|| async {}));
+    /// let (_, routes) = app.into_parts();
+    ///
+    /// let ts = routes.manifest().to_typescript();
+    /// assert!(ts.contains(r#""show": (params: { "id": Param }): string => fill("/users/:id", params)"#));
+    /// ```
+    pub fn to_typescript(&self) -> String {
+        let mut root = Node::default();
+        for (name, route) in &self.routes {
+            let parts: Vec<&str> = if self.separator.is_empty() {
+                vec![name]
+            } else {
+                name.split(self.separator.as_str()).collect()
+            };
+            let node = parts.into_iter().fold(&mut root, |node, part| {
+                node.children.entry(part).or_default()
+            });
+            node.route = Some(route);
+        }
+
+        let mut ts = String::from(HEADER);
+        let base_url = match &self.base_url {
+            Some(url) => string(url),
+            None => "null".to_owned(),
+        };
+        let _ = writeln!(ts, "\nexport const baseUrl: string | null = {base_url};\n");
+        ts.push_str("export const routes = ");
+        write_node(&mut ts, &root, 0);
+        ts.push_str(";\n");
+        ts
+    }
+}
+
+fn write_node(ts: &mut String, node: &Node<'_>, depth: usize) {
+    let object = !node.children.is_empty();
+    match (node.route, object) {
+        (Some(route), true) => {
+            ts.push_str("nest(");
+            write_function(ts, route);
+            ts.push_str(", ");
+        }
+        (Some(route), false) => return write_function(ts, route),
+        (None, _) => {}
+    }
+
+    let indent = "  ".repeat(depth + 1);
+    ts.push_str("{\n");
+    for (key, child) in &node.children {
+        let _ = write!(ts, "{indent}{}: ", string(key));
+        write_node(ts, child, depth + 1);
+        ts.push_str(",\n");
+    }
+    ts.push_str(&"  ".repeat(depth));
+    ts.push('}');
+    if node.route.is_some() {
+        ts.push(')');
+    }
+}
+
+fn write_function(ts: &mut String, route: &ManifestRoute) {
+    let path = string(&route.path);
+    if route.params.is_empty() {
+        let _ = write!(ts, "(): string => {path}");
+        return;
+    }
+    let params: Vec<String> = route
+        .params
+        .iter()
+        .map(|param| format!("{}: Param", string(param)))
+        .collect();
+    let _ = write!(
+        ts,
+        "(params: {{ {} }}): string => fill({path}, params)",
+        params.join("; ")
+    );
+}
+
+/// A TypeScript string literal, JSON strings are valid TypeScript strings
+fn string(value: &str) -> String {
+    serde_json::Value::from(value).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::routing::get;
+
+    use crate::NamedRouter;
+
+    async fn dummy() {}
+
+    #[test]
+    fn typescript() {
+        let users = NamedRouter::<()>::new()
+            .route("index", "/", get(dummy))
+            .route("show", "/:id", get(dummy))
+            .route("files", "/:id/files/*path", get(dummy));
+        let (_, routes) = NamedRouter::new()
+            .route("index", "/", get(dummy))
+            .route("api", "/api", get(dummy))
+            .route("api.status", "/api/status", get(dummy))
+            .nest("users", "/users", users)
+            .into_parts();
+
+        let ts = routes.manifest().to_typescript();
+        let expected = r#"export const baseUrl: string | null = null;
+
+export const routes = {
+  "api": nest((): string => "/api", {
+    "status": (): string => "/api/status",
+  }),
+  "index": (): string => "/",
+  "users": {
+    "files": (params: { "id": Param; "path": Param }): string => fill("/users/:id/files/*path", params),
+    "index": (): string => "/users",
+    "show": (params: { "id": Param }): string => fill("/users/:id", params),
+  },
+};
+"#;
+        assert!(ts.starts_with("// Generated by axum-named-routes"));
+        assert!(ts.ends_with(expected), "{ts}");
+    }
+
+    #[test]
+    fn separator() {
+        let users = NamedRouter::<()>::with_separator("::")
+            .route("index", "/all", get(dummy))
+            .route("name", "/name", get(dummy));
+        let (_, routes) = NamedRouter::with_separator("::")
+            .route("users", "/users", get(dummy))
+            .route("users.csv", "/users.csv", get(dummy))
+            .nest("users", "/users", users)
+            .into_parts();
+
+        let ts = routes.manifest().to_typescript();
+        let expected = r#"export const routes = {
+  "users": nest((): string => "/users", {
+    "index": (): string => "/users/all",
+    "name": (): string => "/users/name",
+  }),
+  "users.csv": (): string => "/users.csv",
+};
+"#;
+        assert!(ts.ends_with(expected), "{ts}");
+    }
+}
@@ -0,0 +1,460 @@
+//! URL generation from route path templates
+//!
+//! Check out [`Routes::url_for`](crate::Routes::url_for) for how this is used
+
+use std::fmt;
+
+use percent_encoding::{utf8_percent_encode, AsciiSet, CONTROLS};
+use serde::{ser::SerializeMap, Serialize, Serializer};
+
+use crate::{RouteNotFound, RoutePath, Segment};
+
+/// The characters that must be percent encoded inside a single path segment.
+/// This is the same set the WHATWG URL spec uses for path segments.
+const PATH_SEGMENT: &AsciiSet = &CONTROLS
+    .add(b' ')
+    .add(b'"')
+    .add(b'#')
+    .add(b'<')
+    .add(b'>')
+    .add(b'`')
+    .add(b'?')
+    .add(b'{')
+    .add(b'}')
+    .add(b'/')
+    .add(b'%');
+
+/// An ordered list of parameters used to fill in a route path template.
+///
+/// Any [`Serialize`] map or struct can be used as parameters directly, this type exists
+/// so parameters can also be collected from an iterator of pairs.
+/// ```
+/// use axum_named_routes::Params;
+///
+/// let params: Params = [("id", 4)].into_iter().collect();
+/// let params = Params::new().insert("id", 4).insert("post_id", "first");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Params(Vec<(String, String)>);
+
+impl Params {
+    /// Create an empty set of parameters
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a parameter, the value is converted using its [`ToString`] implementation
+    pub fn insert<K, V>(mut self, key: K, value: V) -> Self
+    where
+        K: Into<String>,
+        V: ToString,
+    {
+        self.0.push((key.into(), value.to_string()));
+        self
+    }
+
+    /// Get the first value of a parameter
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.0
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Iterate over all parameters in order
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.0.iter().map(|(k, v)| (k.as_str(), v.as_str()))
+    }
+
+    pub(crate) fn into_pairs(self) -> Vec<(String, String)> {
+        self.0
+    }
+}
+
+impl<K, V> FromIterator<(K, V)> for Params
+where
+    K: Into<String>,
+    V: ToString,
+{
+    fn from_iter<T: IntoIterator<Item = (K, V)>>(iter: T) -> Self {
+        Self(
+            iter.into_iter()
+                .map(|(k, v)| (k.into(), v.to_string()))
+                .collect(),
+        )
+    }
+}
+
+impl Serialize for Params {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.0.len()))?;
+        for (k, v) in &self.0 {
+            map.serialize_entry(k, v)?;
+        }
+        map.end()
+    }
+}
+
+/// The error returned when a URL could not be generated for a route
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum UrlError {
+    /// There is no route with the requested name, with similar names like
+    /// [`Routes::try_get`](crate::Routes::try_get)
+    UnknownRoute(RouteNotFound),
+    /// The parameters did not match the placeholders in the route path
+    InvalidParams {
+        /// The name of the route
+        route: String,
+        /// Placeholders in the route path that were not given a value
+        missing: Vec<String>,
+        /// Parameters that do not match any placeholder in the route path
+        unknown: Vec<String>,
+    },
+    /// A parameter value is a `.` or `..` path segment which clients would normalize away
+    DotSegment {
+        /// The name of the route
+        route: String,
+        /// The parameter with the dot segment value
+        param: String,
+    },
+    /// The parameters could not be serialized into key value pairs
+    Serialize(String),
+    /// An absolute URL was requested but no base URL is configured
+    NoBaseUrl,
+}
+
+impl fmt::Display for UrlError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownRoute(err) => err.fmt(f),
+            Self::InvalidParams {
+                route,
+                missing,
+                unknown,
+            } => {
+                write!(f, "invalid parameters for route `{route}`")?;
+                if !missing.is_empty() {
+                    write!(f, ", missing: {}", missing.join(", "))?;
+                }
+                if !unknown.is_empty() {
+                    write!(f, ", unknown: {}", unknown.join(", "))?;
+                }
+                Ok(())
+            }
+            Self::DotSegment { route, param } => write!(
+                f,
+                "parameter `{param}` of route `{route}` is a `.` or `..` path segment"
+            ),
+            Self::Serialize(err) => write!(f, "failed to serialize url parameters: {err}"),
+            Self::NoBaseUrl => f.write_str("no base url is configured for absolute urls"),
+        }
+    }
+}
+
+// `UnknownRoute` is displayed as its `RouteNotFound` so it is not returned as the source,
+// error reports would show the same message twice
+impl std::error::Error for UrlError {}
+
+/// Serialize `params` into a list of key value pairs
+///
+/// This goes through `serde_urlencoded` so anything that can be used as a query string
+/// (structs, maps and sequences of pairs) can be used as parameters.
+pub(crate) fn collect_params<P: Serialize>(params: P) -> Result<Vec<(String, String)>, UrlError> {
+    let encoded =
+        serde_urlencoded::to_string(params).map_err(|err| UrlError::Serialize(err.to_string()))?;
+    Ok(form_urlencoded::parse(encoded.as_bytes())
+        .into_owned()
+        .collect())
+}
+
+/// The result of filling the placeholders of a route path template
+pub(crate) struct FilledPath {
+    /// The path with every placeholder that had a value filled in
+    pub(crate) path: String,
+    /// Placeholders that were not given a value
+    pub(crate) missing: Vec<String>,
+    /// Parameters that were not used by any placeholder
+    pub(crate) rest: Vec<(String, String)>,
+    /// The first placeholder that was given a `.` or `..` value
+    pub(crate) dot_segment: Option<String>,
+}
+
+impl FilledPath {
+    /// Turn into the filled path, treating any leftover parameters as an error
+    pub(crate) fn strict(self, route: &str) -> Result<String, UrlError> {
+        self.check_dot_segment(route)?;
+        if self.missing.is_empty() && self.rest.is_empty() {
+            return Ok(self.path);
+        }
+        let mut unknown: Vec<String> = self.rest.into_iter().map(|(k, _)| k).collect();
+        unknown.dedup();
+        Err(UrlError::InvalidParams {
+            route: route.to_owned(),
+            missing: self.missing,
+            unknown,
+        })
+    }
+
+    /// Turn into the filled path with any leftover parameters appended as a query string
+    pub(crate) fn with_query(self, route: &str) -> Result<String, UrlError> {
+        self.check_dot_segment(route)?;
+        if !self.missing.is_empty() {
+            return Err(UrlError::InvalidParams {
+                route: route.to_owned(),
+                missing: self.missing,
+                unknown: Vec::new(),
+            });
+        }
+        if self.rest.is_empty() {
+            return Ok(self.path);
+        }
+        let mut url = self.path;
+        url.push('?');
+        let start = url.len();
+        Ok(form_urlencoded::Serializer::for_suffix(url, start)
+            .extend_pairs(self.rest)
+            .finish())
+    }
+
+    fn check_dot_segment(&self, route: &str) -> Result<(), UrlError> {
+        match &self.dot_segment {
+            Some(param) => Err(UrlError::DotSegment {
+                route: route.to_owned(),
+                param: param.clone(),
+            }),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Returns true for the segments `.` and `..` which are removed when a URL is normalized
+fn is_dot_segment(segment: &str) -> bool {
+    matches!(segment, "." | "..")
+}
+
+/// Fill the placeholders of `path` using `params`
+///
+/// Each value is percent encoded, wildcard values keep their `/` separators. Percent
+/// encoding does not help for `.` and `..` values since `%2e` is also a dot segment, so
+/// they are reported in [`FilledPath::dot_segment`].
+pub(crate) fn fill_path(path: &RoutePath, mut params: Vec<(String, String)>) -> FilledPath {
+    let mut filled = String::with_capacity(path.as_str().len());
+    let mut missing = Vec::new();
+    let mut dot_segment = None;
+
+    for segment in path.segments() {
+        filled.push('/');
+        let key = match segment {
+            Segment::Static(s) => {
+                filled.push_str(s);
+                continue;
+            }
+            Segment::Param(key) | Segment::Wildcard(key) => key,
+        };
+
+        let Some(pos) = params.iter().position(|(k, _)| k == key) else {
+            missing.push(key.to_owned());
+            continue;
+        };
+        let (_, value) = params.remove(pos);
+        if let Segment::Wildcard(_) = segment {
+            let value = value.trim_start_matches('/');
+            for (i, part) in value.split('/').enumerate() {
+                if i > 0 {
+                    filled.push('/');
+                }
+                if is_dot_segment(part) && dot_segment.is_none() {
+                    dot_segment = Some(key.to_owned());
+                }
+                filled.extend(utf8_percent_encode(part, PATH_SEGMENT));
+            }
+        } else {
+            if is_dot_segment(&value) && dot_segment.is_none() {
+                dot_segment = Some(key.to_owned());
+            }
+            filled.extend(utf8_percent_encode(&value, PATH_SEGMENT));
+        }
+    }
+
+    FilledPath {
+        path: filled,
+        missing,
+        rest: params,
+        dot_segment,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use std::collections::HashMap;
+
+    use crate::{NamedRouter, Params, UrlError};
+    use axum::routing::get;
+    use serde::Serialize;
+
+    async fn dummy() {}
+
+    fn routes() -> crate::Routes {
+        NamedRouter::<()>::new()
+            .route("index", "/", get(dummy))
+            .route("post", "/users/:id/posts/:post_id", get(dummy))
+            .route("files", "/files/*rest", get(dummy))
+            .into_parts()
+            .1
+    }
+
+    #[test]
+    fn fill_params() {
+        #[derive(Serialize)]
+        struct Post {
+            id: u32,
+            post_id: &'static str,
+        }
+
+        let routes = routes();
+        let expected = "/users/4/posts/hello%20world";
+        let map = HashMap::from([("id", "4"), ("post_id", "hello world")]);
+
+        assert_eq!(routes.url_for("index", ()).unwrap(), "/");
+        assert_eq!(routes.url_for("post", &map).unwrap(), expected);
+        assert_eq!(
+            routes
+                .url_for("post", [("id", "4"), ("post_id", "hello world")])
+                .unwrap(),
+            expected
+        );
+        assert_eq!(
+            routes
+                .url_for(
+                    "post",
+                    Post {
+                        id: 4,
+                        post_id: "hello world"
+                    }
+                )
+                .unwrap(),
+            expected
+        );
+        assert_eq!(
+            routes
+                .url_for(
+                    "post",
+                    Params::new().insert("post_id", "a/b").insert("id", 4)
+                )
+                .unwrap(),
+            "/users/4/posts/a%2Fb"
+        );
+        assert_eq!(
+            routes
+                .url_for("files", [("rest", "css/app main.css")])
+                .unwrap(),
+            "/files/css/app%20main.css"
+        );
+    }
+
+    #[test]
+    fn query_params() {
+        #[derive(Serialize)]
+        struct Page {
+            id: u32,
+            page: u32,
+            filter: Option<&'static str>,
+        }
+
+        let routes = routes();
+
+        assert_eq!(
+            routes
+                .url_for_query(
+                    "post",
+                    [("id", "4"), ("post_id", "5"), ("q", "a b&c"), ("q", "d")]
+                )
+                .unwrap(),
+            "/users/4/posts/5?q=a+b%26c&q=d"
+        );
+        assert_eq!(
+            routes
+                .url_for_query(
+                    "index",
+                    Page {
+                        id: 1,
+                        page: 2,
+                        filter: None
+                    }
+                )
+                .unwrap(),
+            "/?id=1&page=2"
+        );
+        assert_eq!(routes.url_for_query("index", ()).unwrap(), "/");
+        assert_eq!(
+            routes.url_for_query("post", [("id", "4")]),
+            Err(UrlError::InvalidParams {
+                route: "post".into(),
+                missing: vec!["post_id".into()],
+                unknown: vec![],
+            })
+        );
+    }
+
+    #[test]
+    fn invalid_params() {
+        let routes = routes();
+
+        let unknown = routes.url_for("posts", ()).unwrap_err();
+        assert_eq!(
+            unknown.to_string(),
+            "Route `posts` does not exist, did you mean `post`?"
+        );
+        assert!(std::error::Error::source(&unknown).is_none());
+        let UrlError::UnknownRoute(err) = unknown else {
+            panic!("expected an unknown route error");
+        };
+        assert_eq!(err.name, "posts");
+        assert_eq!(err.suggestions, ["post"]);
+        assert_eq!(
+            routes.url_for("post", [("id", "4"), ("other", "5")]),
+            Err(UrlError::InvalidParams {
+                route: "post".into(),
+                missing: vec!["post_id".into()],
+                unknown: vec!["other".into()],
+            })
+        );
+        assert!(matches!(
+            routes.url_for("post", "not a map"),
+            Err(UrlError::Serialize(_))
+        ));
+    }
+
+    #[test]
+    fn dot_segments() {
+        let routes = routes();
+        let dot_segment = |param: &str| {
+            Err(UrlError::DotSegment {
+                route: "post".into(),
+                param: param.into(),
+            })
+        };
+
+        assert_eq!(
+            routes.url_for("post", [("id", ".."), ("post_id", "5")]),
+            dot_segment("id")
+        );
+        assert_eq!(
+            routes.url_for_query("post", [("id", "4"), ("post_id", ".")]),
+            dot_segment("post_id")
+        );
+        assert!(matches!(
+            routes.url_for("files", [("rest", "css/../secret")]),
+            Err(UrlError::DotSegment { .. })
+        ));
+        assert_eq!(
+            routes
+                .url_for("post", [("id", "..."), ("post_id", ".a")])
+                .unwrap(),
+            "/users/.../posts/.a"
+        );
+    }
+}